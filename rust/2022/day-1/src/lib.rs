@@ -0,0 +1,89 @@
+use anyhow::{ensure, Context, Result};
+use aoc_common::{parse_token, ParseError, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut acc: i32 = 0;
+        let mut results: Vec<i32> = Vec::new();
+        for (idx, line) in input.lines().enumerate() {
+            match line.len() {
+                0 => {
+                    results.push(acc);
+                    acc = 0;
+                }
+                _ => {
+                    acc += parse_token::<i32>(line)
+                        .map_err(|err| ParseError::new(idx + 1, line, err))?
+                }
+            }
+        }
+
+        // The last elf is not followed by an empty line
+        if acc > 0 {
+            results.push(acc);
+        }
+
+        Ok(results)
+    }
+
+    fn part1(results: &Self::Input) -> Result<Option<Self::Answer1>> {
+        let max = results.iter().max().context("no elves in the input")?;
+
+        Ok(Some(*max))
+    }
+
+    fn part2(results: &Self::Input) -> Result<Option<Self::Answer2>> {
+        ensure!(results.len() >= 3, "less than three elves in the input");
+
+        let mut results = results.clone();
+
+        results.sort_by(|a, b| b.cmp(a)); // Standard reverse sorting; not efficient but quickly to implement
+        Ok(Some(results[0] + results[1] + results[2]))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+    #[test]
+    fn test_example_for_part_1() {
+        assert_eq!(Some(24000), Day1::solve_part1(EXAMPLE).unwrap());
+    }
+
+    #[test]
+    fn test_example_for_part_2() {
+        assert_eq!(Some(45000), Day1::solve_part2(EXAMPLE).unwrap());
+    }
+
+    #[test]
+    fn test_invalid_calories() {
+        let err = Day1::parse("1000\n\n20x0").unwrap_err();
+
+        assert_eq!(
+            "line 3, column 1: invalid value \"20x0\" (invalid digit found in string)",
+            err.to_string()
+        );
+    }
+}
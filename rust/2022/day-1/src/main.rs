@@ -1,34 +1,13 @@
-use std::fs::read_to_string;
+use anyhow::Result;
+use aoc_2022_day_1::Day1;
+use aoc_common::{InputSource, Solution};
 
-// Note: Correct answer for part 1 is: 55621
-// Note: Correct answer for part 2 is: 201491
+fn main() -> Result<()> {
+    let input = InputSource::from_args().read_to_string()?;
+    let results = Day1::parse(&input)?;
 
-fn main() {
-    let filename = "input.txt";
-    let lines: Vec<String> = read_to_string(filename) 
-        .unwrap()
-        .lines()
-        .map(String::from)
-        .collect();
+    println!("{}", Day1::part1(&results)?.unwrap());
+    println!("Part two: {}", Day1::part2(&results)?.unwrap());
 
-    let mut acc: i32 = 0;
-    let mut results: Vec<i32> = Vec::new();
-    for line in lines.iter() {
-        match line.len() {
-            0 => {
-                results.push(acc);
-                acc = 0;
-            },
-            _ => acc += line.parse().unwrap_or(0),
-        }
-    }
-
-    let max: i32 = *results.iter().max().unwrap();
-
-    // Part 2 question
-    results.sort_by(|a, b| b.cmp(a)); // Standard reverse sorting; not efficient but quickly to implement
-    let top_three_sum = results[0] + results[1] + results[2];
-
-    println!("{}", max);
-    println!("Part two: {}", top_three_sum);
-}
\ No newline at end of file
+    Ok(())
+}
@@ -0,0 +1,156 @@
+use anyhow::{Context, Result};
+use aoc_common::{parse_lines, Solution, TokenError};
+use std::convert::Infallible;
+
+pub struct Day2Bis;
+
+impl Solution for Day2Bis {
+    type Input = Vec<(Card, Outcome)>;
+    type Answer1 = Infallible;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_lines(input, parse_challenge)?)
+    }
+
+    fn part2(challenges: &Self::Input) -> Result<Option<Self::Answer2>> {
+        let final_score = challenges
+            .iter()
+            .map(|&(opponent, desired_outcome)| get_outcome_score(opponent, desired_outcome))
+            .sum::<Result<_>>()?;
+
+        Ok(Some(final_score))
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+#[repr(u8)]
+pub enum Card {
+    Rock = 1,     // A  -  value 3
+    Paper = 2,    // B  -  value 2
+    Scissors = 3, // C  -  value 1
+}
+
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+#[repr(u8)]
+pub enum Outcome {
+    Win = 6,  // 6 points - value Z
+    Draw = 3, // 3 points - value Y
+    Loss = 0, // 0 points - value X
+}
+
+fn parse_challenge(challenge: &str) -> Result<(Card, Outcome)> {
+    let (opponent, desired_outcome) = challenge
+        .split_once(' ')
+        .context("failed to parse around ' '")?;
+
+    let opponent = parse_code(opponent, code_to_card)
+        .ok_or_else(|| TokenError::new(opponent, format!("unknown card code {opponent:?}")))?;
+    let desired_outcome = parse_code(desired_outcome, code_to_outcome).ok_or_else(|| {
+        TokenError::new(
+            desired_outcome,
+            format!("unknown outcome code {desired_outcome:?}"),
+        )
+    })?;
+
+    Ok((opponent, desired_outcome))
+}
+
+fn parse_code<T>(code: &str, code_to_value: fn(char) -> Option<T>) -> Option<T> {
+    let mut chars = code.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(code), None) => code_to_value(code),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+fn get_score(challenge: &str) -> Result<i32> {
+    let (opponent, desired_outcome) = parse_challenge(challenge)?;
+
+    get_outcome_score(opponent, desired_outcome)
+}
+
+fn get_outcome_score(opponent: Card, desired_outcome: Outcome) -> Result<i32> {
+    let played_card = get_player_card(&opponent, &desired_outcome)
+        .context("no card to play for the challenge")?;
+
+    Ok((desired_outcome as u8 + played_card as u8) as i32)
+}
+
+fn get_player_card(opponent: &Card, desired_outcome: &Outcome) -> Option<Card> {
+    if *desired_outcome == Outcome::Draw {
+        // Is actually the best practice to implement Copy for Card; or it's possible to avoid to waste memory here?
+        return Some(*opponent);
+    }
+
+    match (desired_outcome, opponent) {
+        (Outcome::Win, Card::Rock) => Some(Card::Paper),
+        (Outcome::Win, Card::Paper) => Some(Card::Scissors),
+        (Outcome::Win, Card::Scissors) => Some(Card::Rock),
+        (Outcome::Loss, Card::Rock) => Some(Card::Scissors),
+        (Outcome::Loss, Card::Paper) => Some(Card::Rock),
+        (Outcome::Loss, Card::Scissors) => Some(Card::Paper),
+        _ => None,
+    }
+}
+
+// Could these two methods be defined with a custom method within the Enum like "Card::from_code(char)"?
+fn code_to_card(code: char) -> Option<Card> {
+    match code {
+        'A' => Some(Card::Rock),
+        'B' => Some(Card::Paper),
+        'C' => Some(Card::Scissors),
+        _ => None,
+    }
+}
+
+fn code_to_outcome(code: char) -> Option<Outcome> {
+    match code {
+        'X' => Some(Outcome::Loss),
+        'Y' => Some(Outcome::Draw),
+        'Z' => Some(Outcome::Win),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cards_and_outcomes() {
+        assert_eq!(code_to_card('A'), Some(Card::Rock));
+        assert_eq!(code_to_card('B'), Some(Card::Paper));
+        assert_eq!(code_to_card('C'), Some(Card::Scissors));
+        assert_eq!(code_to_outcome('X'), Some(Outcome::Loss));
+        assert_eq!(code_to_outcome('Y'), Some(Outcome::Draw));
+        assert_eq!(code_to_outcome('Z'), Some(Outcome::Win));
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(Some(12), Day2Bis::solve_part2("A Y\nB X\nC Z").unwrap());
+    }
+
+    #[test]
+    fn test_score() {
+        assert_eq!(get_score("A Y").unwrap(), 4); // Must draw
+        assert_eq!(get_score("B X").unwrap(), 1); // Must lose
+        assert_eq!(get_score("C Z").unwrap(), 7); // Must win
+
+        assert_eq!(get_score("B Z").unwrap(), 9); // Must win
+        assert_eq!(get_score("A Z").unwrap(), 8); // Must win
+    }
+
+    #[test]
+    fn test_unknown_outcome() {
+        let err = Day2Bis::parse("A Y\nB W").unwrap_err();
+
+        assert_eq!(
+            "line 2, column 3: unknown outcome code \"W\"",
+            err.to_string()
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use anyhow::{Context, Result};
+use aoc_common::{parse_lines, Solution, TokenError};
+use std::convert::Infallible;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<(Card, Card)>;
+    type Answer1 = i32;
+    type Answer2 = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_lines(input, parse_challenge)?)
+    }
+
+    fn part1(challenges: &Self::Input) -> Result<Option<Self::Answer1>> {
+        let final_score = challenges
+            .iter()
+            .map(|&(opponent, player)| get_card_score(opponent, player))
+            .sum::<Result<_>>()?;
+
+        Ok(Some(final_score))
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+#[repr(u8)]
+pub enum Card {
+    Rock = 1,     // A, X  -  value 3
+    Paper = 2,    // B, Y  -  value 2
+    Scissors = 3, // C, Z  -  value 1
+}
+
+#[repr(u8)]
+enum Outcome {
+    Win = 6,  // 6 points
+    Draw = 3, // 3 points
+    Loss = 0, // 0 points
+}
+
+fn parse_challenge(challenge: &str) -> Result<(Card, Card)> {
+    let (opponent, player) = challenge
+        .split_once(' ')
+        .context("failed to parse around ' '")?;
+
+    Ok((parse_card(opponent)?, parse_card(player)?))
+}
+
+fn parse_card(code: &str) -> Result<Card> {
+    let mut chars = code.chars();
+
+    match (chars.next().and_then(code_to_card), chars.next()) {
+        (Some(card), None) => Ok(card),
+        _ => Err(TokenError::new(code, format!("unknown card code {code:?}")).into()),
+    }
+}
+
+#[cfg(test)]
+fn get_score(challenge: &str) -> Result<i32> {
+    let (opponent, player) = parse_challenge(challenge)?;
+
+    get_card_score(opponent, player)
+}
+
+fn get_card_score(opponent: Card, player: Card) -> Result<i32> {
+    let outcome = get_outcome(&player, &opponent).context("no outcome for the challenge")?;
+
+    Ok((outcome as u8 + player as u8) as i32)
+}
+
+fn get_outcome(player: &Card, opponent: &Card) -> Option<Outcome> {
+    if player == opponent {
+        return Some(Outcome::Draw);
+    }
+
+    match (player, opponent) {
+        (Card::Rock, Card::Scissors) => Some(Outcome::Win),
+        (Card::Scissors, Card::Paper) => Some(Outcome::Win),
+        (Card::Paper, Card::Rock) => Some(Outcome::Win),
+        (Card::Scissors, Card::Rock) => Some(Outcome::Loss),
+        (Card::Paper, Card::Scissors) => Some(Outcome::Loss),
+        (Card::Rock, Card::Paper) => Some(Outcome::Loss),
+        _ => None,
+    }
+}
+
+fn code_to_card(code: char) -> Option<Card> {
+    match code {
+        'A' | 'X' => Some(Card::Rock),
+        'B' | 'Y' => Some(Card::Paper),
+        'C' | 'Z' => Some(Card::Scissors),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cards() {
+        assert_eq!(code_to_card('A'), Some(Card::Rock));
+        assert_eq!(code_to_card('B'), Some(Card::Paper));
+        assert_eq!(code_to_card('C'), Some(Card::Scissors));
+        assert_eq!(code_to_card('X'), Some(Card::Rock));
+        assert_eq!(code_to_card('Y'), Some(Card::Paper));
+        assert_eq!(code_to_card('Z'), Some(Card::Scissors));
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(Some(15), Day2::solve_part1("A Y\nB X\nC Z").unwrap());
+    }
+
+    #[test]
+    fn test_score() {
+        assert_eq!(get_score("A Y").unwrap(), 8); // Win
+        assert_eq!(get_score("B X").unwrap(), 1); // Loss
+        assert_eq!(get_score("C Z").unwrap(), 6); // Draw
+
+        assert_eq!(get_score("B Z").unwrap(), 9); // Win
+        assert_eq!(get_score("A Z").unwrap(), 3); // Loss
+    }
+
+    #[test]
+    fn test_unknown_card() {
+        let err = Day2::parse("A Y\nB Q").unwrap_err();
+
+        assert_eq!("line 2, column 3: unknown card code \"Q\"", err.to_string());
+    }
+}
@@ -0,0 +1,122 @@
+use anyhow::{Context, Result};
+use aoc_common::{parse_lines, Solution, TokenError};
+use std::collections::HashSet;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_lines(input, |line| {
+            if let Some((pos, _)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+                return Err(TokenError::new(&line[pos..], "items must be letters").into());
+            }
+
+            Ok(String::from(line))
+        })?)
+    }
+
+    fn part1(rucksacks: &Self::Input) -> Result<Option<Self::Answer1>> {
+        let priorities = rucksacks
+            .iter()
+            .enumerate()
+            .map(|(idx, x)| {
+                let (comp_a, comp_b) = x.split_at(x.len() / 2);
+                let elems_comp_a: HashSet<char> = comp_a.chars().collect();
+                let elems_comp_b: HashSet<char> = comp_b.chars().collect();
+
+                let mut unique = elems_comp_a.intersection(&elems_comp_b);
+
+                elem_to_priority(unique.next().with_context(|| {
+                    format!("no item in both compartments of rucksack {}", idx + 1)
+                })?)
+            })
+            .sum::<Result<_>>()?;
+
+        Ok(Some(priorities))
+    }
+
+    fn part2(rucksacks: &Self::Input) -> Result<Option<Self::Answer2>> {
+        let priorities = rucksacks
+            .chunks(3)
+            .enumerate()
+            .map(|(idx, x)| {
+                let common_elements = x
+                    .iter()
+                    .map(|line| line.chars().collect::<HashSet<char>>())
+                    .reduce(|acc, set| &acc & &set)
+                    .unwrap_or_default();
+
+                elem_to_priority(
+                    common_elements
+                        .iter()
+                        .collect::<Vec<_>>()
+                        .first()
+                        .with_context(|| format!("no badge in common for group {}", idx + 1))?,
+                )
+            })
+            .sum::<Result<_>>()?;
+
+        Ok(Some(priorities))
+    }
+}
+
+fn elem_to_priority(elem: &char) -> Result<u32> {
+    let mut priorities = ('a'..='z').chain('A'..='Z');
+    let position = priorities
+        .position(|x| x == *elem)
+        .with_context(|| format!("no priority for item {elem:?}"))?;
+
+    Ok(position as u32 + 1)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_common::{assert_answer, unlocked_input};
+
+    #[test]
+    fn test_demo_input_for_part_1() {
+        assert_eq!(
+            Some(157),
+            Day3::solve_part1(include_str!("../demo-input.txt")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_demo_input_for_part_2() {
+        assert_eq!(
+            Some(70),
+            Day3::solve_part2(include_str!("../demo-input.txt")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid_item() {
+        let err =
+            Day3::parse("vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGL4rsFMfFZSrLrFZsSL").unwrap_err();
+
+        assert_eq!("line 2, column 17: items must be letters", err.to_string());
+    }
+
+    #[test]
+    fn test_input_for_part_1() {
+        let Some(input) = unlocked_input("input.txt") else {
+            return;
+        };
+
+        assert_answer(2022, 3, 1, Day3::solve_part1(&input).unwrap());
+    }
+
+    #[test]
+    fn test_input_for_part_2() {
+        let Some(input) = unlocked_input("input.txt") else {
+            return;
+        };
+
+        assert_answer(2022, 3, 2, Day3::solve_part2(&input).unwrap());
+    }
+}
@@ -0,0 +1,55 @@
+use anyhow::{Context, Result};
+use aoc_common::Solution;
+use std::convert::Infallible;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(lines: &Self::Input) -> Result<Option<Self::Answer1>> {
+        let calibration = lines
+            .iter()
+            .map(|line| line.chars().filter(char::is_ascii_digit).collect())
+            .enumerate()
+            .map(|(idx, digits): (_, Vec<_>)| {
+                let a = digits
+                    .first()
+                    .with_context(|| format!("no digits in line {}", idx + 1))?;
+                let b = digits.last().unwrap();
+
+                Ok(a.to_digit(10).unwrap() * 10 + b.to_digit(10).unwrap())
+            })
+            .sum::<Result<_>>()?;
+
+        Ok(Some(calibration))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_common::{assert_answer, unlocked_input};
+
+    #[test]
+    fn test_example() {
+        let example = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet";
+
+        assert_eq!(Some(142), Day1::solve_part1(example).unwrap());
+    }
+
+    #[test]
+    fn solution() {
+        let Some(input) = unlocked_input("input.txt") else {
+            return;
+        };
+
+        assert_answer(2023, 1, 1, Day1::solve_part1(&input).unwrap());
+    }
+}
@@ -1,29 +1,11 @@
-use std::fs;
+use anyhow::Result;
+use aoc_2023_day_1::Day1;
+use aoc_common::{InputSource, Solution};
 
-fn solve() -> u32 {
-    fs::read_to_string("input.txt")
-        .unwrap()
-        .lines()
-        .map(|line| line.chars().filter(char::is_ascii_digit).collect())
-        .map(|digits: Vec<_>| {
-            let a = digits.first().unwrap();
-            let b = digits.last().unwrap();
+fn main() -> Result<()> {
+    let input = InputSource::from_args().read_to_string()?;
 
-            a.to_digit(10).unwrap() * 10 + b.to_digit(10).unwrap()
-        })
-        .sum()
-}
-
-fn main() {
-    println!("{}", solve());
-}
-
-#[cfg(test)]
-mod test {
-    use super::solve;
+    println!("{}", Day1::part1(&Day1::parse(&input)?)?.unwrap());
 
-    #[test]
-    fn solution() {
-        assert_eq!(55621, solve());
-    }
+    Ok(())
 }
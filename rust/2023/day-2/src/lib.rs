@@ -0,0 +1,178 @@
+use anyhow::{Context, Error, Result};
+use aoc_common::{parse_lines, parse_token, Solution, TokenError};
+use itertools::Itertools;
+use std::str::FromStr;
+
+type Amount = u32;
+type Id = u32;
+
+#[derive(Debug)]
+pub struct Game {
+    id: Id,
+    sets: Vec<Set>,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Set {
+    blue: Amount,
+    red: Amount,
+    green: Amount,
+}
+
+impl Set {
+    fn is_possible(&self) -> bool {
+        self.red <= 12 && self.green <= 13 && self.blue <= 14
+    }
+
+    fn max(&self, other: &Set) -> Self {
+        Self {
+            red: other.red.max(self.red),
+            green: other.green.max(self.green),
+            blue: other.blue.max(self.blue),
+        }
+    }
+}
+
+impl FromStr for Game {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (game, sets) = s
+            .splitn(2, ": ")
+            .collect_tuple()
+            .context("failed to parse around ':'")?;
+
+        let (_, id) = game
+            .splitn(2, ' ')
+            .collect_tuple()
+            .context("failed to parse around ' '")?;
+
+        let id = parse_token(id).context("failed to parse game id")?;
+
+        let sets = sets
+            .split("; ")
+            .map(str::parse)
+            .collect::<Result<_>>()
+            .context("failed to parse game sets")?;
+
+        Ok(Game { id, sets })
+    }
+}
+
+impl FromStr for Set {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut set = Set::default();
+
+        for ss in s.split(", ") {
+            let (amount, color) = ss
+                .split(' ')
+                .collect_tuple()
+                .context("failed to parse set")?;
+
+            let amount = parse_token(amount).context("failed to parse cubes amount")?;
+
+            match color {
+                "blue" => set.blue = amount,
+                "red" => set.red = amount,
+                "green" => set.green = amount,
+                _ => {
+                    return Err(
+                        TokenError::new(color, format!("unknown cube color {color}")).into(),
+                    )
+                }
+            }
+        }
+
+        Ok(set)
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_lines(input, |line| {
+            line.parse::<Game>().context("failed to parse game")
+        })?)
+    }
+
+    fn part1(games: &Self::Input) -> Result<Option<Self::Answer1>> {
+        let ids = games
+            .iter()
+            .filter(|game| game.sets.iter().all(Set::is_possible))
+            .map(|game| game.id)
+            .sum();
+
+        Ok(Some(ids))
+    }
+
+    fn part2(games: &Self::Input) -> Result<Option<Self::Answer2>> {
+        let powers = games
+            .iter()
+            .map(|Game { sets, .. }| {
+                let Set { red, green, blue } =
+                    sets.iter().fold(Set::default(), |acc, set| acc.max(set));
+
+                red * green * blue
+            })
+            .sum();
+
+        Ok(Some(powers))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_common::{assert_answer, unlocked_input};
+
+    const EXAMPLE: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    #[test]
+    fn test_example_for_part1() {
+        assert_eq!(Some(8), Day2::solve_part1(EXAMPLE).unwrap());
+    }
+
+    #[test]
+    fn test_example_for_part2() {
+        assert_eq!(Some(2286), Day2::solve_part2(EXAMPLE).unwrap());
+    }
+
+    #[test]
+    fn test_unknown_color() {
+        let err = Day2::parse("Game 1: 3 blue, 4 red\nGame 2: 1 blue, 2 yellow").unwrap_err();
+
+        assert_eq!(
+            "line 2, column 19: failed to parse game: failed to parse game sets: unknown cube color yellow",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_solve_part1() {
+        let Some(input) = unlocked_input("input.txt") else {
+            return;
+        };
+
+        assert_answer(2023, 2, 1, Day2::solve_part1(&input).unwrap());
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        let Some(input) = unlocked_input("input.txt") else {
+            return;
+        };
+
+        assert_answer(2023, 2, 2, Day2::solve_part2(&input).unwrap());
+    }
+}
@@ -0,0 +1,1482 @@
+use anyhow::{bail, Context, Error, Result};
+use aoc_common::{math::gcd, parse_lines, parse_token, Solution};
+use itertools::{self, Itertools};
+use rayon::prelude::*;
+use std::{
+    cmp::Ordering,
+    convert::Infallible,
+    fmt::{self, Display},
+    ops::{Neg, RangeInclusive},
+    str::FromStr,
+};
+
+#[derive(Copy, Clone, Debug)]
+pub struct Hail {
+    x: i64,
+    y: i64,
+    z: i64,
+    vx: i64,
+    vy: i64,
+    vz: i64,
+}
+
+impl FromStr for Hail {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        // Example input "19, 13, 30 @ -2,  1, -2"
+        //                x   y   z    vx  vy  vz
+
+        let (pos, vel) = s
+            .splitn(2, " @ ")
+            .collect_tuple()
+            .context("failed to parse around '@'")?;
+
+        let (x, y, z) = pos
+            .split(',')
+            .map(str::trim)
+            .map(parse_token::<i64>)
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .collect_tuple()
+            .context("failed to parse position values")?;
+        let (vx, vy, vz) = vel
+            .split(',')
+            .map(str::trim)
+            .map(parse_token::<i64>)
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .collect_tuple()
+            .context("failed to parse velocity values")?;
+
+        Ok(Hail {
+            x,
+            y,
+            z,
+            vx,
+            vy,
+            vz,
+        })
+    }
+}
+
+impl Hail {
+    fn intersect_2d_cramer(&self, hail_2: &Hail) -> Option<(f64, f64)> {
+        /*
+            Approach A) Simple intersection using matrix algebra and Cramer's rule
+
+            For example, considering the first Hail:
+            19, 13, 30 @ -2,  1, -2
+
+            (x:19, y:13; speed x: -1, speed y: 1)
+
+            We already have the direction of the hail as an explicit line:
+
+            y = mx + q
+            => (1)y = (-1)x + (0)q
+
+            Then we need to make the "starting" position of the hail pass through
+            the same line.
+
+            Since q (the intercept) is 0, the gradient (m) can be calculated by
+            dividing the v_y by v_x:
+
+            m = 1 / -1 = -1
+
+            We rewrite the line in its implicit form:
+
+            a1 x + b1 y = c1
+
+            a1 is the gradient with the opposed sign (since y = mx + q => -mx +y = q)
+            b1 is always 1 since we are "solving" for the x variable
+
+            c1, the intercept, must be calculated in order to have the hail starting point
+            passing through the line:
+
+            Since y = mx + q, q = y - mx:
+
+            c1 = y - (gradient * x)
+
+        */
+
+        let gradient_1: f64 = self.vy as f64 / self.vx as f64;
+        let gradient_2: f64 = hail_2.vy as f64 / hail_2.vx as f64;
+
+        let a1 = -gradient_1;
+        let a2 = -gradient_2;
+        let b1 = 1.0;
+        let b2 = 1.0;
+
+        let c1 = self.y as f64 - (gradient_1 * self.x as f64);
+        let c2 = hail_2.y as f64 - (gradient_2 * hail_2.x as f64);
+
+        /*
+            Now we just rewrite the simple system of two lines in a matrix and apply the Cramer's rule:
+
+            [a1 b1] [x] = [c1]
+            [a2 b2] [y]   [c2]
+
+            Obviously, if the determinant is 0 the hails never collide (lines are parallel) and we skip them.
+        */
+
+        let determinant = (a1 * b2) - (b1 * a2);
+        if !determinant.is_normal() {
+            return None;
+        }
+
+        let x: f64 = ((c1 * b2) - (b1 * c2)) / determinant;
+        let y: f64 = ((a1 * c2) - (c1 * a2)) / determinant;
+
+        /*
+            Lastly, we check whether the collision happened in the past
+            by analyzing the signum of the found point in comparison
+            of the starting point and the speed direction
+
+            [shorthand for:
+                if (self.vx > 0 && x < self.x)
+                || (self.vx < 0 && x > self.x)
+                || (hail_2.vx > 0 && x < hail_2.x)
+                || (hail_2.vx < 0 && x > hail_2.x)
+            ]
+        */
+
+        if ((x - self.x as f64).signum() != self.vx.signum() as f64)
+            || ((x - hail_2.x as f64).signum() != hail_2.vx.signum() as f64)
+        {
+            return None; // Collision happened in the past
+        }
+
+        Some((x, y))
+    }
+
+    fn intersect_2d_line_segments(&self, hail_2: &Hail) -> Option<(Rational, Rational)> {
+        /*
+           Approach B) Using numerical analysis to find the intersection point of two segments
+
+           This approach uses the algorithm proposed by Paul Bourke (see: https://paulbourke.net/geometry/pointlineplane/)
+           Here, instead of solving for the infinite line, only analyze the intersection point between two segments
+
+           The segments are calculated as: starting point + ending point; where the ending point is the position
+           of the Hail after one nanosecond passed. Longer segments would only scale ua and ub, so one nanosecond
+           is enough: the parameters are then the very times at which each hail reaches the intersection.
+
+           Algorithm:
+
+           Given two segments defined as (where P1..Pn are the x,y points coordinates):
+           Pa = P1 + ua ( P2 - P1 )
+           Pb = P3 + ub ( P4 - P3 )
+
+           Solving both for Pa = Pb (the intersection point):
+
+           x1 + ua (x2 - x1) = x3 + ub (x4 - x3)
+           y1 + ua (y2 - y1) = y3 + ub (y4 - y3)
+
+           Then, solve for either ua or ub, and input the resulting value in:
+
+           x = x1 + ua (x2 - x1)
+           y = y1 + ua (y2 - y1)
+
+           Everything is computed exactly with i128 integers, keeping ua, x and y as fractions: with positions
+           around 4e14 and velocities around 1e3 the largest numerator is in the order of 1e21, way below
+           the i128 limit.
+        */
+
+        let (x1, y1) = (self.x as i128, self.y as i128);
+        let (x3, y3) = (hail_2.x as i128, hail_2.y as i128);
+
+        // P2 - P1 and P4 - P3
+        let (dx1, dy1) = (self.vx as i128, self.vy as i128);
+        let (dx3, dy3) = (hail_2.vx as i128, hail_2.vy as i128);
+
+        let denominator = (dy3 * dx1) - (dx3 * dy1);
+
+        if denominator == 0 {
+            return None; // Parallel lines
+        }
+
+        let ua = Rational::new((dx3 * (y1 - y3)) - (dy3 * (x1 - x3)), denominator);
+        let ub = Rational::new((dx1 * (y1 - y3)) - (dy1 * (x1 - x3)), denominator);
+
+        if ua.is_negative() || ub.is_negative() {
+            return None; // Collision happened in the past
+        }
+
+        let x = Rational::new(x1 * ua.den + ua.num * dx1, ua.den);
+        let y = Rational::new(y1 * ua.den + ua.num * dy1, ua.den);
+
+        Some((x, y))
+    }
+}
+
+/// Exact fraction, always kept in lowest terms with a positive denominator
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+impl Rational {
+    pub fn new(num: i128, den: i128) -> Rational {
+        assert!(den != 0, "denominator must not be zero");
+
+        let divisor = gcd(num, den) as i128 * den.signum();
+        Rational {
+            num: num / divisor,
+            den: den / divisor,
+        }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.num
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.den
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        self.num as f64 / self.den as f64
+    }
+
+    fn is_negative(&self) -> bool {
+        self.num < 0
+    }
+
+    fn floor(&self) -> i128 {
+        self.num.div_euclid(self.den)
+    }
+
+    fn ceil(&self) -> i128 {
+        -(-self.num).div_euclid(self.den)
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.num * other.den).cmp(&(other.num * self.den))
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.den {
+            1 => write!(f, "{}", self.num),
+            den => write!(f, "{}/{}", self.num, den),
+        }
+    }
+}
+
+/// Coordinate of an intersection point, as found by one of the approaches
+trait Coordinate {
+    fn within(&self, start: u64, end: u64) -> bool;
+}
+
+impl Coordinate for f64 {
+    fn within(&self, start: u64, end: u64) -> bool {
+        (start as f64..=end as f64).contains(self)
+    }
+}
+
+impl Coordinate for Rational {
+    fn within(&self, start: u64, end: u64) -> bool {
+        (start as i128 * self.den..=end as i128 * self.den).contains(&self.num)
+    }
+}
+
+fn count_intersections<C: Coordinate>(
+    hails: &[Hail],
+    test_area_start: u64,
+    test_area_end: u64,
+    intersect_method: fn(&Hail, &Hail) -> Option<(C, C)>,
+) -> u64 {
+    hails
+        .iter()
+        .tuple_combinations()
+        .map(|(hail_1, hail_2)| match intersect_method(hail_1, hail_2) {
+            Some((x, y))
+                if x.within(test_area_start, test_area_end)
+                    && y.within(test_area_start, test_area_end) =>
+            {
+                1
+            }
+            _ => 0,
+        })
+        .sum()
+}
+
+/// Part of the trajectory of a hail that lies within the test area, from now
+/// on, as far as approach C needs it to pick the rows of the grid it crosses
+struct Segment<'a> {
+    hail: &'a Hail,
+    /// Rows spanned by the segment, rounded outwards to integers
+    y_range: RangeInclusive<i128>,
+}
+
+impl<'a> Segment<'a> {
+    fn clip(hail: &'a Hail, test_area_start: u64, test_area_end: u64) -> Option<Segment<'a>> {
+        if hail.vx == 0 && hail.vy == 0 {
+            return None; // A still hail never crosses a path
+        }
+
+        let (area_start, area_end) = (test_area_start as i128, test_area_end as i128);
+        let mut start = Rational::new(0, 1);
+        let mut end: Option<Rational> = None;
+
+        for (position, velocity) in [(hail.x, hail.vx), (hail.y, hail.vy)] {
+            let (position, velocity) = (position as i128, velocity as i128);
+
+            if velocity == 0 {
+                if !(area_start..=area_end).contains(&position) {
+                    return None;
+                }
+                continue;
+            }
+
+            let enter = Rational::new(area_start - position, velocity);
+            let leave = Rational::new(area_end - position, velocity);
+            start = start.max(enter.min(leave));
+            end = Some(end.map_or(enter.max(leave), |end| end.min(enter.max(leave))));
+        }
+
+        // At least one of the velocities isn't zero, thus the hail leaves the area
+        let end = end?;
+        if start > end {
+            return None;
+        }
+
+        let at = |time: Rational| {
+            Rational::new(
+                hail.y as i128 * time.den + hail.vy as i128 * time.num,
+                time.den,
+            )
+        };
+        let (first, last) = (at(start), at(end));
+
+        Some(Segment {
+            hail,
+            y_range: first.min(last).floor()..=first.max(last).ceil(),
+        })
+    }
+}
+
+/// Fraction compared by cross-multiplication, without reducing it to lowest
+/// terms: unlike a [`Rational`] it costs no gcd, which matters in the inner
+/// loops of approach C
+#[derive(Copy, Clone, Debug)]
+struct Fraction {
+    num: i128,
+    /// Always positive
+    den: i128,
+}
+
+impl Fraction {
+    fn new(num: i128, den: i128) -> Fraction {
+        if den < 0 {
+            Fraction {
+                num: -num,
+                den: -den,
+            }
+        } else {
+            Fraction { num, den }
+        }
+    }
+
+    fn integer(num: i128) -> Fraction {
+        Fraction { num, den: 1 }
+    }
+}
+
+impl Neg for Fraction {
+    type Output = Fraction;
+
+    fn neg(self) -> Fraction {
+        Fraction {
+            num: -self.num,
+            den: self.den,
+        }
+    }
+}
+
+impl PartialEq for Fraction {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Fraction {}
+
+impl PartialOrd for Fraction {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Fraction {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.num * other.den).cmp(&(other.num * self.den))
+    }
+}
+
+/// Line of the grid of approach C, at `at + sign * ε` for an infinitesimal ε.
+///
+/// Shifted that way, no hail starts on a line, and two hails only cross it at
+/// the same point when they move along the same line: every intersection lies
+/// strictly within a single cell. Horizontal lines are shifted by a δ that is
+/// negligible even next to ε, so that no hail goes through a corner either.
+#[derive(Copy, Clone, Debug)]
+struct GridLine {
+    at: i128,
+    sign: i128,
+}
+
+impl GridLine {
+    /// Whether the line lies past the given coordinate
+    fn exceeds(&self, coordinate: Fraction) -> bool {
+        match coordinate.cmp(&Fraction::integer(self.at)) {
+            Ordering::Equal => self.sign > 0,
+            ordering => ordering == Ordering::Less,
+        }
+    }
+}
+
+/// Lines splitting the test area into `cells` stripes, or fewer when the
+/// area is narrower; the outer ones are just outside the area, whose borders
+/// are part of it
+fn grid_lines(test_area_start: u64, test_area_end: u64, cells: usize) -> Vec<GridLine> {
+    let (start, end) = (test_area_start as i128, test_area_end as i128);
+    let cells = (cells as i128).clamp(1, (end - start).max(1));
+
+    let mut lines = vec![GridLine {
+        at: start,
+        sign: -1,
+    }];
+    lines.extend((1..cells).map(|k| GridLine {
+        at: start + (end - start) * k / cells,
+        sign: 1,
+    }));
+    lines.push(GridLine { at: end, sign: 1 });
+    lines
+}
+
+/// Time along the trajectory of a hail, plus the infinitesimals of the grid
+/// line it crosses then, if any; only their signs are kept, as a hail never
+/// crosses two vertical (or two horizontal) lines at the same time
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Moment {
+    time: Fraction,
+    epsilon: i128,
+    delta: i128,
+}
+
+impl Moment {
+    const START: Moment = Moment {
+        time: Fraction { num: 0, den: 1 },
+        epsilon: 0,
+        delta: 0,
+    };
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Side {
+    Bottom,
+    Right,
+    Top,
+    Left,
+}
+
+/// Point on the border of a cell, ordered counterclockwise from the bottom
+/// left corner, where no trajectory passes
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct BorderPoint {
+    side: Side,
+    /// Coordinate along the side, then the infinitesimal shift of the line
+    along: Fraction,
+    shift: Fraction,
+}
+
+impl BorderPoint {
+    fn new(side: Side, along: Fraction, shift: Fraction) -> BorderPoint {
+        match side {
+            Side::Bottom | Side::Right => BorderPoint { side, along, shift },
+            Side::Top | Side::Left => BorderPoint {
+                side,
+                along: -along,
+                shift: -shift,
+            },
+        }
+    }
+}
+
+/// Trajectories within a cell of the grid
+#[derive(Default)]
+struct Cell<'a> {
+    /// Where the trajectories going through the whole cell enter and leave it
+    chords: Vec<(BorderPoint, BorderPoint)>,
+    /// Hails of the chords
+    crossing: Vec<&'a Hail>,
+    /// Hails starting within the cell
+    starting: Vec<&'a Hail>,
+}
+
+/// Splits the trajectory of a hail within a row of the grid, between the
+/// lines `bottom` and `top`, into the cells it goes through
+fn trace_row<'a>(
+    hail: &'a Hail,
+    columns: &[GridLine],
+    (bottom, top): (GridLine, GridLine),
+    cells: &mut [Cell<'a>],
+) {
+    let (x, y) = (hail.x as i128, hail.y as i128);
+    let (vx, vy) = (hail.vx as i128, hail.vy as i128);
+    let last = columns.len() - 1;
+
+    let cross_column = |line: GridLine| Moment {
+        time: Fraction::new(line.at - x, vx),
+        epsilon: line.sign * vx.signum(),
+        delta: 0,
+    };
+    let cross_row = |line: GridLine| Moment {
+        time: Fraction::new(line.at - y, vy),
+        epsilon: 0,
+        delta: line.sign * vy.signum(),
+    };
+    let position = |time: Fraction| {
+        (
+            Fraction::new(x * time.den + vx * time.num, time.den),
+            Fraction::new(y * time.den + vy * time.num, time.den),
+        )
+    };
+    let column_point = |side, moment: Moment, line: GridLine| {
+        BorderPoint::new(
+            side,
+            position(moment.time).1,
+            Fraction::new(vy * line.sign, vx),
+        )
+    };
+    let row_point = |side, moment: Moment, line: GridLine| {
+        BorderPoint::new(
+            side,
+            position(moment.time).0,
+            Fraction::new(vx * line.sign, vy),
+        )
+    };
+
+    // When the trajectory enters the row within the test area, and through
+    // which column line, if any; then when it leaves them
+    let mut enter = (Moment::START, None);
+    let mut leave: Option<Moment> = None;
+
+    if vx == 0 {
+        let x = Fraction::integer(x);
+        if columns[0].exceeds(x) || !columns[last].exceeds(x) {
+            return;
+        }
+    } else {
+        let (from, to) = if vx > 0 { (0, last) } else { (last, 0) };
+        let moment = cross_column(columns[from]);
+        if moment > enter.0 {
+            enter = (moment, Some(from));
+        }
+        leave = Some(cross_column(columns[to]));
+    }
+
+    let row_leave = match vy {
+        0 => {
+            let y = Fraction::integer(y);
+            if bottom.exceeds(y) || !top.exceeds(y) {
+                return;
+            }
+            None
+        }
+        _ => {
+            let (from, to) = if vy > 0 { (bottom, top) } else { (top, bottom) };
+            let moment = cross_row(from);
+            if moment > enter.0 {
+                enter = (moment, None);
+            }
+            Some((cross_row(to), to))
+        }
+    };
+    if let Some((moment, _)) = row_leave {
+        leave = Some(leave.map_or(moment, |leave| leave.min(moment)));
+    }
+
+    // Still hails are never traced, so the trajectory leaves the row
+    let Some(leave) = leave else {
+        return;
+    };
+    let (enter, through_column) = enter;
+    if enter >= leave {
+        return;
+    }
+
+    let (enter_side, leave_side) = match vx > 0 {
+        true => (Side::Left, Side::Right),
+        false => (Side::Right, Side::Left),
+    };
+    let (mut column, mut from) = match through_column {
+        Some(line) => (
+            if vx > 0 { line } else { line - 1 },
+            Some(column_point(enter_side, enter, columns[line])),
+        ),
+        None => {
+            let column = columns.partition_point(|line| !line.exceeds(position(enter.time).0)) - 1;
+            let from = (enter != Moment::START).then(|| {
+                let (side, line) = if vy > 0 {
+                    (Side::Bottom, bottom)
+                } else {
+                    (Side::Top, top)
+                };
+                row_point(side, enter, line)
+            });
+            (column, from)
+        }
+    };
+
+    loop {
+        let next_line = match vx.signum() {
+            0 => None,
+            1 => Some(column + 1),
+            _ => Some(column),
+        };
+        let next = next_line
+            .map(|line| (line, cross_column(columns[line])))
+            .filter(|&(_, moment)| moment <= leave);
+
+        let to = match next {
+            Some((line, moment)) => column_point(leave_side, moment, columns[line]),
+            None => {
+                // The trajectory doesn't reach the next column, thus it leaves the row
+                let (moment, line) = row_leave.expect("the trajectory leaves the row");
+                let side = if vy > 0 { Side::Top } else { Side::Bottom };
+                row_point(side, moment, line)
+            }
+        };
+
+        let cell = &mut cells[column];
+        match from {
+            Some(from) => {
+                cell.chords.push((from, to));
+                cell.crossing.push(hail);
+            }
+            None => cell.starting.push(hail),
+        }
+
+        match next {
+            Some((line, moment)) if line != 0 && line != last => {
+                from = Some(column_point(enter_side, moment, columns[line]));
+                column = if vx > 0 { column + 1 } else { column - 1 };
+            }
+            _ => break,
+        }
+    }
+}
+
+/// Pairs of chords of a convex cell that cross each other, i.e. whose ends
+/// alternate along its border.
+///
+/// Going around the border, each chord is open from its first end to its
+/// second one; when a chord closes, the chords opened after it and still open
+/// cross it. Open chords are kept in a Fenwick tree, by the position of their
+/// first end.
+fn count_crossing_chords(chords: &[(BorderPoint, BorderPoint)]) -> u64 {
+    // Ends of the chords, in order along the border: the same chord running
+    // twice (i.e. two hails on the same line) is nested rather than crossing
+    let mut ends: Vec<(BorderPoint, usize, bool)> = Vec::with_capacity(chords.len() * 2);
+    for (idx, &(a, b)) in chords.iter().enumerate() {
+        ends.push((a.min(b), idx, false));
+        ends.push((a.max(b), usize::MAX - idx, true));
+    }
+    ends.sort_unstable_by(|a, b| a.0.cmp(&b.0).then(a.1.cmp(&b.1)));
+
+    let mut opened_at = vec![0; chords.len()];
+    let mut tree = vec![0i64; ends.len() + 1];
+    let update = |tree: &mut [i64], mut idx: usize, value: i64| {
+        while idx < tree.len() {
+            tree[idx] += value;
+            idx += idx & idx.wrapping_neg();
+        }
+    };
+    let prefix = |tree: &[i64], mut idx: usize| {
+        let mut sum = 0;
+        while idx > 0 {
+            sum += tree[idx];
+            idx &= idx - 1;
+        }
+        sum
+    };
+
+    let mut count = 0;
+    for (position, &(_, idx, closing)) in ends.iter().enumerate() {
+        let position = position + 1;
+        if closing {
+            let opened = opened_at[usize::MAX - idx];
+            count += (prefix(&tree, position) - prefix(&tree, opened)) as u64;
+            update(&mut tree, opened, -1);
+        } else {
+            opened_at[idx] = position;
+            update(&mut tree, position, 1);
+        }
+    }
+
+    count
+}
+
+/// Whether two hails cross paths in the future within the given cell, with
+/// the same rules as approach B
+fn crosses_within(
+    hail_1: &Hail,
+    hail_2: &Hail,
+    (left, right): (GridLine, GridLine),
+    (bottom, top): (GridLine, GridLine),
+) -> bool {
+    let (x1, y1) = (hail_1.x as i128, hail_1.y as i128);
+    let (x3, y3) = (hail_2.x as i128, hail_2.y as i128);
+    let (dx1, dy1) = (hail_1.vx as i128, hail_1.vy as i128);
+    let (dx3, dy3) = (hail_2.vx as i128, hail_2.vy as i128);
+
+    let denominator = (dy3 * dx1) - (dx3 * dy1);
+    if denominator == 0 {
+        return false;
+    }
+
+    // Times at which each hail reaches the intersection point, as fractions
+    // over the same denominator, made positive
+    let sign = denominator.signum();
+    let denominator = denominator.abs();
+    let ua = ((dx3 * (y1 - y3)) - (dy3 * (x1 - x3))) * sign;
+    let ub = ((dx1 * (y1 - y3)) - (dy1 * (x1 - x3))) * sign;
+    if ua < 0 || ub < 0 {
+        return false;
+    }
+
+    let x = Fraction::new(x1 * denominator + ua * dx1, denominator);
+    let y = Fraction::new(y1 * denominator + ua * dy1, denominator);
+
+    !left.exceeds(x) && right.exceeds(x) && !bottom.exceeds(y) && top.exceeds(y)
+}
+
+/// Counts the intersections like approach B, splitting the test area into a
+/// grid of cells, so that each intersection lies within exactly one of them.
+///
+/// Within a cell, trajectories going through the whole cell cross each other
+/// when their ends alternate along its border, which is counted without
+/// checking each pair; only the trajectories of the hails starting within the
+/// cell are checked one by one. With c cells per side, tracing and counting
+/// the chords take about n·c steps and the checks about n²/c, hence about
+/// √n/4 cells per side, which measured fastest. Rows of the grid run in
+/// parallel.
+fn count_intersections_grid(hails: &[Hail], test_area_start: u64, test_area_end: u64) -> u64 {
+    let segments: Vec<Segment> = hails
+        .iter()
+        .filter_map(|hail| Segment::clip(hail, test_area_start, test_area_end))
+        .collect();
+
+    let cells = ((segments.len() as f64).sqrt() / 4.0).ceil() as usize;
+    let lines = grid_lines(test_area_start, test_area_end, cells);
+
+    lines
+        .par_windows(2)
+        .map(|row| {
+            let (bottom, top) = (row[0], row[1]);
+            let mut cells: Vec<Cell> = lines.windows(2).map(|_| Cell::default()).collect();
+
+            for segment in &segments {
+                if *segment.y_range.start() <= top.at && *segment.y_range.end() >= bottom.at {
+                    trace_row(segment.hail, &lines, (bottom, top), &mut cells);
+                }
+            }
+
+            cells
+                .iter()
+                .zip(lines.windows(2))
+                .map(|(cell, column)| {
+                    let column = (column[0], column[1]);
+                    let starting = cell
+                        .starting
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, hail)| {
+                            cell.crossing
+                                .iter()
+                                .chain(&cell.starting[idx + 1..])
+                                .filter(|other| crosses_within(hail, other, column, (bottom, top)))
+                                .count() as u64
+                        })
+                        .sum::<u64>();
+
+                    count_crossing_chords(&cell.chords) + starting
+                })
+                .sum::<u64>()
+        })
+        .sum()
+}
+
+/// Position or velocity in 3D, widened to avoid overflowing the cross products
+pub type Vector = [i128; 3];
+
+/// Moment two hails are the closest, from now on
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Approach {
+    pub time: Rational,
+    /// Square of the distance, which unlike the distance itself is exact
+    pub distance_squared: Rational,
+}
+
+impl Approach {
+    pub fn distance(&self) -> f64 {
+        self.distance_squared.to_f64().sqrt()
+    }
+}
+
+impl Hail {
+    pub fn position(&self) -> Vector {
+        [self.x, self.y, self.z].map(i128::from)
+    }
+
+    pub fn velocity(&self) -> Vector {
+        [self.vx, self.vy, self.vz].map(i128::from)
+    }
+
+    fn from_vectors(position: Vector, velocity: Vector) -> Result<Hail> {
+        let [x, y, z, vx, vy, vz] = [
+            position[0],
+            position[1],
+            position[2],
+            velocity[0],
+            velocity[1],
+            velocity[2],
+        ]
+        .map(i64::try_from);
+
+        Ok(Hail {
+            x: x?,
+            y: y?,
+            z: z?,
+            vx: vx?,
+            vy: vy?,
+            vz: vz?,
+        })
+    }
+}
+
+impl Hail {
+    /// Position of the hail after `time` nanoseconds, in 3D
+    pub fn position_at(&self, time: Rational) -> Result<[Rational; 3]> {
+        let scaled = add_scaled(
+            self.position().map(|x| x * time.den),
+            self.velocity(),
+            time.num,
+        )?;
+
+        Ok(scaled.map(|x| Rational::new(x, time.den)))
+    }
+
+    /// Time and distance of the closest approach between two hails, in 3D.
+    ///
+    /// Hails moving apart are the closest right now, at time 0.
+    pub fn closest_approach(&self, hail_2: &Hail) -> Result<Approach> {
+        /*
+            The gap between the two hails at time t is g - t * w, where g is
+            the gap at time 0 and w the speed at which it closes. Its squared
+            length is a parabola in t, with its minimum at t = (g · w) / (w · w);
+            there, the squared distance is |g x w|² / (w · w) (Lagrange's identity).
+        */
+
+        let gap = sub(hail_2.position(), self.position());
+        let closing_speed = sub(self.velocity(), hail_2.velocity());
+
+        let speed_squared = dot(closing_speed, closing_speed)?;
+        let gap_times_speed = dot(gap, closing_speed)?;
+
+        if speed_squared == 0 || gap_times_speed <= 0 {
+            return Ok(Approach {
+                time: Rational::new(0, 1),
+                distance_squared: Rational::new(dot(gap, gap)?, 1),
+            });
+        }
+
+        let normal = cross(gap, closing_speed)?;
+        Ok(Approach {
+            time: Rational::new(gap_times_speed, speed_squared),
+            distance_squared: Rational::new(dot(normal, normal)?, speed_squared),
+        })
+    }
+
+    /// Time at which two hails collide in 3D, if they ever do
+    pub fn collision(&self, hail_2: &Hail) -> Result<Option<Rational>> {
+        let approach = self.closest_approach(hail_2)?;
+
+        Ok((approach.distance_squared.num == 0).then_some(approach.time))
+    }
+}
+
+fn dot(a: Vector, b: Vector) -> Result<i128> {
+    a.iter()
+        .zip(b)
+        .try_fold(0i128, |acc, (x, y)| acc.checked_add(x.checked_mul(y)?))
+        .context("dot product overflows an i128")
+}
+
+fn sub(a: Vector, b: Vector) -> Vector {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn cross(a: Vector, b: Vector) -> Result<Vector> {
+    let component = |i: usize, j: usize| -> Option<i128> {
+        a[i].checked_mul(b[j])?.checked_sub(a[j].checked_mul(b[i])?)
+    };
+
+    match (component(1, 2), component(2, 0), component(0, 1)) {
+        (Some(x), Some(y), Some(z)) => Ok([x, y, z]),
+        _ => bail!("cross product overflows an i128"),
+    }
+}
+
+/// Divides all the components by their gcd, keeping the direction of the vector
+fn reduce(vector: Vector) -> Vector {
+    let divisor = gcd(gcd(vector[0], vector[1]) as i128, vector[2]) as i128;
+    if divisor == 0 {
+        return vector;
+    }
+
+    vector.map(|x| x / divisor)
+}
+
+/// Time at which a hail, moving from `position` with `velocity`, lies on the
+/// line through the origin with direction `direction`
+fn time_on_line(position: Vector, velocity: Vector, direction: Vector) -> Result<Option<i128>> {
+    // (position + t * velocity) x direction = 0, for each component where
+    // velocity x direction isn't zero
+    let numerator = cross(position, direction)?;
+    let denominator = cross(velocity, direction)?;
+
+    Ok((0..3)
+        .find(|&i| denominator[i] != 0)
+        .filter(|&i| numerator[i] % denominator[i] == 0)
+        .map(|i| -numerator[i] / denominator[i]))
+}
+
+/// Finds the rock, thrown at an integer position with an integer velocity, that
+/// hits every hail.
+///
+/// In the reference frame of the first hail, that hail stands still at the
+/// origin, so the rock must pass through the origin. The rock must also hit
+/// the second and the third hail, thus its line lies on both the plane through
+/// the origin and the line of the second hail, and the plane through the
+/// origin and the line of the third hail: the direction of the rock is the
+/// cross product of the normals of the two planes.
+///
+/// Knowing that direction, the times of the two hits follow from a linear
+/// equation, and from the two hit points the velocity and the starting
+/// position of the rock. Everything is solved exactly over i128 integers; any
+/// division with a remainder means the hails don't admit such a rock.
+fn throw_rock(hails: &[Hail]) -> Result<Hail> {
+    let reference = hails.first().context("there are no hails to hit")?;
+
+    // Hails whose lines are parallel, or that collide with the reference hail,
+    // don't fix a single direction, and hails too fast for the cross products
+    // overflow them; the first pair giving a rock that hits every hail is enough.
+    for (first, second) in hails.iter().skip(1).tuple_combinations() {
+        let relative = |hail: &Hail| {
+            (
+                sub(hail.position(), reference.position()),
+                sub(hail.velocity(), reference.velocity()),
+            )
+        };
+        let (p1, v1) = relative(first);
+        let (p2, v2) = relative(second);
+
+        let (Ok(normal_1), Ok(normal_2)) = (cross(p1, v1), cross(p2, v2)) else {
+            continue;
+        };
+        let Ok(direction) = cross(reduce(normal_1), reduce(normal_2)) else {
+            continue;
+        };
+        let direction = reduce(direction);
+        if direction == [0, 0, 0] {
+            continue;
+        }
+
+        let (Ok(Some(t1)), Ok(Some(t2))) = (
+            time_on_line(p1, v1, direction),
+            time_on_line(p2, v2, direction),
+        ) else {
+            continue;
+        };
+        if t1 == t2 {
+            continue;
+        }
+
+        // Hit points, back in the original reference frame
+        let (Ok(hit_1), Ok(hit_2)) = (
+            add_scaled(first.position(), first.velocity(), t1),
+            add_scaled(second.position(), second.velocity(), t2),
+        ) else {
+            continue;
+        };
+
+        let elapsed = t2 - t1;
+        let distance = sub(hit_2, hit_1);
+        if distance.iter().any(|x| x % elapsed != 0) {
+            continue;
+        }
+
+        let velocity = distance.map(|x| x / elapsed);
+        let Ok(position) = add_scaled(hit_1, velocity, -t1) else {
+            continue;
+        };
+        // A rock outside the hail coordinates can't be the one hitting them all
+        let Ok(rock) = Hail::from_vectors(position, velocity) else {
+            continue;
+        };
+
+        if replay_throw(&rock, hails).is_err() {
+            continue;
+        }
+
+        return Ok(rock);
+    }
+
+    bail!("no rock thrown from an integer position hits every hail")
+}
+
+fn add_scaled(position: Vector, velocity: Vector, t: i128) -> Result<Vector> {
+    let component = |i: usize| position[i].checked_add(velocity[i].checked_mul(t)?);
+
+    match (component(0), component(1), component(2)) {
+        (Some(x), Some(y), Some(z)) => Ok([x, y, z]),
+        _ => bail!("position overflows an i128"),
+    }
+}
+
+/// Replays the throw, checking that the rock hits each hail at a non-negative
+/// integer time; returns the time of each hit.
+fn replay_throw(rock: &Hail, hails: &[Hail]) -> Result<Vec<i128>> {
+    hails
+        .iter()
+        .enumerate()
+        .map(|(idx, hail)| match rock.collision(hail)? {
+            Some(time) if time.den == 1 => Ok(time.num),
+            _ => bail!("the rock misses hail {}", idx + 1),
+        })
+        .collect()
+}
+
+fn parse_hails(input: &str) -> Result<Vec<Hail>> {
+    Ok(parse_lines(input, |line| {
+        line.parse::<Hail>().context("failed to parse hail values")
+    })?)
+}
+
+const TEST_AREA_START: u64 = 200_000_000_000_000;
+const TEST_AREA_END: u64 = 400_000_000_000_000;
+
+/// Solves the puzzle intersecting hails with approach A (Cramer's rule)
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<Hail>;
+    type Answer1 = u64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_hails(input)
+    }
+
+    fn part1(hails: &Self::Input) -> Result<Option<Self::Answer1>> {
+        Ok(Some(count_intersections(
+            hails,
+            TEST_AREA_START,
+            TEST_AREA_END,
+            Hail::intersect_2d_cramer,
+        )))
+    }
+
+    fn part2(hails: &Self::Input) -> Result<Option<Self::Answer2>> {
+        let rock = throw_rock(hails)?;
+
+        Ok(Some(rock.x + rock.y + rock.z))
+    }
+}
+
+/// Solves the puzzle intersecting hails with approach B (line segments), in
+/// exact integer arithmetic
+pub struct Day24LineSegments;
+
+impl Solution for Day24LineSegments {
+    type Input = Vec<Hail>;
+    type Answer1 = u64;
+    type Answer2 = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_hails(input)
+    }
+
+    fn part1(hails: &Self::Input) -> Result<Option<Self::Answer1>> {
+        Ok(Some(count_intersections(
+            hails,
+            TEST_AREA_START,
+            TEST_AREA_END,
+            Hail::intersect_2d_line_segments,
+        )))
+    }
+}
+
+/// Solves the puzzle intersecting hails with approach C (a grid over the test
+/// area, counting the crossings within each cell, in parallel)
+pub struct Day24Grid;
+
+impl Solution for Day24Grid {
+    type Input = Vec<Hail>;
+    type Answer1 = u64;
+    type Answer2 = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_hails(input)
+    }
+
+    fn part1(hails: &Self::Input) -> Result<Option<Self::Answer1>> {
+        Ok(Some(count_intersections_grid(
+            hails,
+            TEST_AREA_START,
+            TEST_AREA_END,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_common::{assert_answer, unlocked_input};
+    use std::time::Instant;
+
+    #[test]
+    fn test_demo_input_for_part_1() {
+        assert_eq!(
+            2,
+            count_intersections(
+                &parse_hails(include_str!("../demo-input.txt")).unwrap(),
+                7,
+                27,
+                Hail::intersect_2d_cramer
+            )
+        );
+    }
+
+    #[test]
+    fn test_demo_input_for_part_1_with_line_segments() {
+        assert_eq!(
+            2,
+            count_intersections(
+                &parse_hails(include_str!("../demo-input.txt")).unwrap(),
+                7,
+                27,
+                Hail::intersect_2d_line_segments
+            )
+        );
+    }
+
+    #[test]
+    fn test_demo_input_for_part_1_with_grid() {
+        assert_eq!(
+            2,
+            count_intersections_grid(
+                &parse_hails(include_str!("../demo-input.txt")).unwrap(),
+                7,
+                27
+            )
+        );
+    }
+
+    /// Pseudo-random hails, starting within `positions` on both axes and
+    /// moving at most `speed` along each, some of them still on one axis
+    fn random_hails(seed: u64, count: usize, positions: (i64, i64), speed: i64) -> Vec<Hail> {
+        let mut seed = seed;
+        let mut next = |modulus: i64| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 1) as i64 % modulus
+        };
+        let (from, to) = positions;
+        (0..count)
+            .map(|_| Hail {
+                x: from + next(to - from + 1),
+                y: from + next(to - from + 1),
+                z: 0,
+                vx: next(2 * speed + 1) - speed,
+                vy: next(2 * speed + 1) - speed,
+                vz: 0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_grid_matches_all_pairs() {
+        let hails = random_hails(24, 3000, (0, 999), 10);
+
+        assert_eq!(
+            count_intersections(&hails, 200, 800, Hail::intersect_2d_line_segments),
+            count_intersections_grid(&hails, 200, 800)
+        );
+    }
+
+    #[test]
+    fn test_grid_matches_all_pairs_with_ties() {
+        // Hails packed in a small area: many of them start on the grid lines,
+        // cross them at the same point or run along the same line
+        for seed in 0..300 {
+            let hails = random_hails(seed, 1 + seed as usize, (-2, 42), 3);
+            let (start, end) = (seed % 10, 10 + seed % 30);
+
+            assert_eq!(
+                count_intersections(&hails, start, end, Hail::intersect_2d_line_segments),
+                count_intersections_grid(&hails, start, end),
+                "seed {seed}"
+            );
+        }
+    }
+
+    /// Took 7.5s on a single core, in release mode
+    #[test]
+    #[ignore]
+    fn test_grid_runtime() {
+        let hails = random_hails(
+            7,
+            100_000,
+            (TEST_AREA_START as i64, TEST_AREA_END as i64),
+            500,
+        );
+
+        let start = Instant::now();
+        let count = count_intersections_grid(&hails, TEST_AREA_START, TEST_AREA_END);
+        eprintln!(
+            "{count} intersections between 100000 hails in {:.2?}",
+            start.elapsed()
+        );
+    }
+
+    #[test]
+    fn test_exact_intersection() {
+        let hails = parse_hails("19, 13, 30 @ -2, 1, -2\n18, 19, 22 @ -1, -1, -2").unwrap();
+
+        assert_eq!(
+            Some((Rational::new(43, 3), Rational::new(46, 3))),
+            hails[0].intersect_2d_line_segments(&hails[1])
+        );
+    }
+
+    #[test]
+    fn test_exact_intersection_in_the_past() {
+        let hails = parse_hails("19, 13, 30 @ -2, 1, -2\n20, 19, 15 @ 1, -5, -3").unwrap();
+
+        assert_eq!(None, hails[0].intersect_2d_line_segments(&hails[1]));
+    }
+
+    #[test]
+    fn test_exact_intersection_with_vertical_hail() {
+        let hails = parse_hails("0, 0, 0 @ 0, 1, 0\n-5, 5, 0 @ 1, 0, 0").unwrap();
+
+        assert_eq!(
+            Some((Rational::new(0, 1), Rational::new(5, 1))),
+            hails[0].intersect_2d_line_segments(&hails[1])
+        );
+    }
+
+    #[test]
+    fn test_exact_intersection_on_test_area_border() {
+        // The first pair crosses exactly on the border, the second one a third
+        // of a unit beyond it
+        let on_border = parse_hails(
+            "399999999999999, 300000000000000, 0 @ 3, 1, 0
+399999999999999, 300000000000001, 0 @ 3, -2, 0",
+        )
+        .unwrap();
+        let beyond_border = parse_hails(
+            "399999999999999, 300000000000000, 0 @ 4, 1, 0
+399999999999999, 300000000000001, 0 @ 4, -2, 0",
+        )
+        .unwrap();
+
+        for (hails, expected) in [(on_border, 1), (beyond_border, 0)] {
+            assert_eq!(
+                expected,
+                count_intersections(
+                    &hails,
+                    TEST_AREA_START,
+                    TEST_AREA_END,
+                    Hail::intersect_2d_line_segments
+                )
+            );
+            assert_eq!(
+                expected,
+                count_intersections_grid(&hails, TEST_AREA_START, TEST_AREA_END)
+            );
+        }
+    }
+
+    #[test]
+    fn test_demo_input_for_part_2() {
+        let hails = parse_hails(include_str!("../demo-input.txt")).unwrap();
+        let rock = throw_rock(&hails).unwrap();
+
+        assert_eq!((24, 13, 10), (rock.x, rock.y, rock.z));
+        assert_eq!((-3, 1, 2), (rock.vx, rock.vy, rock.vz));
+        assert_eq!(vec![5, 3, 4, 6, 1], replay_throw(&rock, &hails).unwrap());
+        assert_eq!(
+            Some(47),
+            Day24::solve_part2(include_str!("../demo-input.txt")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_no_rock_hits_every_hail() {
+        let input = format!("{}\n0, 0, 0 @ 1, 1, 1", include_str!("../demo-input.txt"));
+        let hails = parse_hails(&input).unwrap();
+
+        assert_eq!(
+            "no rock thrown from an integer position hits every hail",
+            throw_rock(&hails).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_skip_overflowing_pair() {
+        // The first two hails after the reference one move so fast that the
+        // directions of their planes overflow when crossed; the later ones
+        // still give the rock
+        let rock = Hail {
+            x: 24,
+            y: 13,
+            z: 10,
+            vx: 700_000_001_111,
+            vy: -500_000_000_333,
+            vz: 300_000_000_777,
+        };
+        let hit_at = |t: i64, [vx, vy, vz]: [i64; 3]| Hail {
+            x: rock.x + t * (rock.vx - vx),
+            y: rock.y + t * (rock.vy - vy),
+            z: rock.z + t * (rock.vz - vz),
+            vx,
+            vy,
+            vz,
+        };
+        let hails = [
+            hit_at(1, [0, 0, 0]),
+            hit_at(
+                200_000,
+                [900_000_000_001, 800_000_000_017, -700_000_000_019],
+            ),
+            hit_at(
+                200_003,
+                [-600_000_000_031, 500_000_000_041, 900_000_000_053],
+            ),
+            hit_at(5, [-3, 1, 2]),
+            hit_at(9, [1, -2, -1]),
+            hit_at(13, [2, 1, -3]),
+        ];
+
+        let found = throw_rock(&hails).unwrap();
+        assert_eq!(rock.position(), found.position());
+        assert_eq!(rock.velocity(), found.velocity());
+    }
+
+    #[test]
+    fn test_replay_missed_hail() {
+        let hails = parse_hails(include_str!("../demo-input.txt")).unwrap();
+        let rock = parse_hails("24, 13, 10 @ -3, 1, 1").unwrap()[0];
+
+        assert_eq!(
+            "the rock misses hail 1",
+            replay_throw(&rock, &hails).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_position_at() {
+        let hail = parse_hails("19, 13, 30 @ -2, 1, -2").unwrap()[0];
+
+        assert_eq!(
+            [9, 18, 20].map(|x| Rational::new(x, 1)),
+            hail.position_at(Rational::new(5, 1)).unwrap()
+        );
+        assert_eq!(
+            "18 27/2 29",
+            hail.position_at(Rational::new(1, 2))
+                .unwrap()
+                .map(|x| x.to_string())
+                .join(" ")
+        );
+    }
+
+    #[test]
+    fn test_collisions_with_rock() {
+        let hails = parse_hails(include_str!("../demo-input.txt")).unwrap();
+        let rock = parse_hails("24, 13, 10 @ -3, 1, 2").unwrap()[0];
+
+        let times: Vec<_> = hails
+            .iter()
+            .map(|hail| rock.collision(hail).unwrap())
+            .collect();
+
+        assert_eq!(
+            [5, 3, 4, 6, 1].map(|t| Some(Rational::new(t, 1))).to_vec(),
+            times
+        );
+        // Hails only cross paths in the XY plane
+        assert_eq!(None, hails[0].collision(&hails[1]).unwrap());
+    }
+
+    #[test]
+    fn test_closest_approach() {
+        let hails = parse_hails("0, 0, 0 @ 1, 0, 0\n10, 3, 4 @ -1, 0, 0").unwrap();
+        let approach = hails[0].closest_approach(&hails[1]).unwrap();
+
+        assert_eq!(Rational::new(5, 1), approach.time);
+        assert_eq!(5.0, approach.distance());
+
+        // Moving apart, the hails are the closest right away
+        let hails = parse_hails("0, 0, 0 @ -1, 0, 0\n10, 3, 4 @ 1, 0, 0").unwrap();
+        let approach = hails[0].closest_approach(&hails[1]).unwrap();
+
+        assert_eq!(Rational::new(0, 1), approach.time);
+        assert_eq!(Rational::new(125, 1), approach.distance_squared);
+    }
+
+    #[test]
+    fn test_invalid_velocity() {
+        let err = Day24::parse("19, 13, 30 @ -2, 1, -2\n18, 19, 22 @ -1, -1, -2z").unwrap_err();
+
+        assert_eq!(
+            "line 2, column 22: failed to parse hail values: invalid value \"-2z\" (invalid digit found in string)",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_solve_part_1() {
+        let Some(input) = unlocked_input("input.txt") else {
+            return;
+        };
+
+        assert_answer(2023, 24, 1, Day24::solve_part1(&input).unwrap());
+        assert_answer(2023, 24, 1, Day24LineSegments::solve_part1(&input).unwrap());
+        assert_answer(2023, 24, 1, Day24Grid::solve_part1(&input).unwrap());
+    }
+
+    #[test]
+    fn test_solve_part_2() {
+        let Some(input) = unlocked_input("input.txt") else {
+            return;
+        };
+
+        assert_answer(2023, 24, 2, Day24::solve_part2(&input).unwrap());
+    }
+}
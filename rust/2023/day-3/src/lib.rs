@@ -0,0 +1,191 @@
+use anyhow::Result;
+use aoc_common::{parse_token, Grid, Neighbours, ParseError, Solution, Span};
+
+// Problem constrains:
+// - Part schematic is a square matrix
+// - Part numbers DO repeat
+
+#[derive(Debug, PartialEq, Clone)]
+struct PartNumber {
+    value: u32,
+    span: Span,
+}
+
+/// Engine schematic, together with the numbers found in it
+#[derive(Debug)]
+pub struct Schematic {
+    grid: Grid<char>,
+    numbers: Vec<PartNumber>,
+    /// Index of the number covering each cell, if any
+    number_at: Grid<Option<usize>>,
+}
+
+impl Schematic {
+    fn new(grid: Grid<char>) -> Result<Schematic, ParseError> {
+        let numbers = grid
+            .runs(char::is_ascii_digit)
+            .into_iter()
+            .map(|span| {
+                let digits: String = span.positions().map(|position| grid[position]).collect();
+                let value = parse_token(&digits)
+                    .map_err(|err| ParseError::at(span.row + 1, span.cols.start + 1, err))?;
+
+                Ok(PartNumber { value, span })
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        let mut number_at = grid.map(|_| None);
+        for (idx, number) in numbers.iter().enumerate() {
+            for position in number.span.positions() {
+                number_at[position] = Some(idx);
+            }
+        }
+
+        Ok(Schematic {
+            grid,
+            numbers,
+            number_at,
+        })
+    }
+
+    /// Numbers adjacent to a symbol, even diagonally
+    fn part_numbers(&self) -> impl Iterator<Item = &PartNumber> {
+        self.numbers.iter().filter(|number| {
+            number.span.positions().any(|position| {
+                self.grid
+                    .neighbours(position, Neighbours::Eight)
+                    .any(|neighbour| is_part_symbol(self.grid[neighbour]))
+            })
+        })
+    }
+
+    /// Products of the two numbers adjacent to each gear; "*" symbols adjacent
+    /// to any other count of numbers are not gears
+    fn gear_ratios(&self) -> impl Iterator<Item = u32> + '_ {
+        self.grid
+            .iter()
+            .filter(|&(_, &cell)| cell == '*')
+            .filter_map(|(position, _)| {
+                let mut adjacent: Vec<usize> = self
+                    .grid
+                    .neighbours(position, Neighbours::Eight)
+                    .filter_map(|neighbour| self.number_at[neighbour])
+                    .collect();
+                adjacent.sort_unstable();
+                adjacent.dedup();
+
+                match adjacent[..] {
+                    [first, second] => Some(self.numbers[first].value * self.numbers[second].value),
+                    _ => None,
+                }
+            })
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Schematic;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Schematic::new(Grid::parse(input, Ok)?)?)
+    }
+
+    fn part1(schematic: &Self::Input) -> Result<Option<Self::Answer1>> {
+        Ok(Some(schematic.part_numbers().map(|x| x.value).sum()))
+    }
+
+    fn part2(schematic: &Self::Input) -> Result<Option<Self::Answer2>> {
+        Ok(Some(schematic.gear_ratios().sum()))
+    }
+}
+
+fn is_part_symbol(char: char) -> bool {
+    !char.is_ascii_digit() && char != '.'
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn part_numbers(input: &str) -> Vec<u32> {
+        Day3::parse(input)
+            .unwrap()
+            .part_numbers()
+            .map(|x| x.value)
+            .collect()
+    }
+
+    #[test]
+    fn test_number_parsing() {
+        let schematic = Day3::parse("467..114.....*........35..633.......#...617*....33").unwrap();
+        let numbers: Vec<(u32, usize, usize)> = schematic
+            .numbers
+            .iter()
+            .map(|x| (x.value, x.span.cols.start, x.span.cols.end))
+            .collect();
+
+        assert_eq!(
+            vec![
+                (467, 0, 3),
+                (114, 5, 8),
+                (35, 22, 24),
+                (633, 26, 29),
+                (617, 40, 43),
+                (33, 48, 50)
+            ],
+            numbers
+        );
+    }
+
+    #[test]
+    fn test_valid_parts() {
+        let valid_parts = part_numbers(
+            "...*......
+..35..633.
+......#...",
+        );
+
+        assert!(valid_parts.contains(&35));
+        assert!(valid_parts.contains(&633));
+    }
+
+    #[test]
+    fn test_valid_parts_bottom_line() {
+        let valid_parts = part_numbers(
+            "...$.*....
+.664.598..",
+        );
+
+        assert!(valid_parts.contains(&664));
+        assert!(valid_parts.contains(&598));
+    }
+
+    #[test]
+    fn test_demo_input_for_part_1() {
+        assert_eq!(
+            Some(4361),
+            Day3::solve_part1(include_str!("../demo-input.txt")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_demo_input_for_part_2() {
+        assert_eq!(
+            Some(467835),
+            Day3::solve_part2(include_str!("../demo-input.txt")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_part_number_overflow() {
+        let err = Day3::parse("...*..........\n..99999999999.").unwrap_err();
+
+        assert_eq!(
+            "line 2, column 3: invalid value \"99999999999\" (number too large to fit in target type)",
+            err.to_string()
+        );
+    }
+}
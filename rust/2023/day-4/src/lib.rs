@@ -0,0 +1,315 @@
+use anyhow::{ensure, Context, Error, Result};
+use aoc_common::{parse_lines, parse_token, Solution};
+use itertools::Itertools;
+use std::{convert::Infallible, str::FromStr};
+
+#[derive(Debug, Clone)]
+pub struct ScratchCard {
+    id: usize,
+    winning_count: usize,
+}
+
+impl FromStr for ScratchCard {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (id, numbers) = s
+            .splitn(2, ": ")
+            .collect_tuple()
+            .context("failed to parse around ':'")?;
+
+        let (_, id) = id
+            .split_ascii_whitespace()
+            .collect_tuple()
+            .context("failed to parse around ' '")?;
+
+        let id = parse_token(id).context("failed to parse scratchcard id")?;
+
+        let (winning_numbers, scratched_numbers) = numbers
+            .splitn(2, " | ")
+            .map(|x| {
+                x.split_ascii_whitespace()
+                    .map(parse_token)
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()
+            .context("failed to parse scratchcard numbers")?
+            .into_iter()
+            .collect_tuple()
+            .context("failed to parse around '|'")?;
+
+        Ok(ScratchCard::new(id, &winning_numbers, &scratched_numbers))
+    }
+}
+
+impl ScratchCard {
+    fn new(id: usize, winning_numbers: &[u8], scratched_numbers: &[u8]) -> Self {
+        let winning_count = winning_numbers
+            .iter()
+            .filter(|n| scratched_numbers.contains(n))
+            .count();
+
+        Self { id, winning_count }
+    }
+
+    fn score(&self) -> Result<u64> {
+        match self.winning_count {
+            0 => Ok(0),
+            n => u32::try_from(n - 1)
+                .ok()
+                .and_then(|exp| 2u64.checked_pow(exp))
+                .with_context(|| format!("the score of scratchcard {} overflows an u64", self.id)),
+        }
+    }
+}
+
+/// Counts the scratchcards in one pass: each card wins one copy of each of the
+/// following `winning_count` cards for every copy of it, so the copies it
+/// hands out can be added once to a running total and taken out again past
+/// the last card it wins.
+fn count_cards(cards: &[ScratchCard]) -> Result<u64> {
+    // Copies handed out by the cards seen so far to the current card, and
+    // the ones that stop being handed out at each card
+    let mut won_copies: u64 = 0;
+    let mut expiring = vec![0u64; cards.len() + 1];
+    let mut total: u64 = 0;
+
+    for (idx, card) in cards.iter().enumerate() {
+        ensure!(
+            card.id == idx + 1,
+            "scratchcard {} found in place of scratchcard {}",
+            card.id,
+            idx + 1
+        );
+
+        won_copies -= expiring[idx];
+        let copies = won_copies.checked_add(1).context(OVERFLOW)?;
+        total = total.checked_add(copies).context(OVERFLOW)?;
+
+        if card.winning_count > 0 {
+            let last_won = (idx + card.winning_count).min(cards.len() - 1);
+            won_copies = won_copies.checked_add(copies).context(OVERFLOW)?;
+            expiring[last_won + 1] += copies;
+        }
+    }
+
+    Ok(total)
+}
+
+const OVERFLOW: &str = "the number of scratchcards overflows an u64";
+
+/// Counts the scratchcards by simulating every single copy won; way slower,
+/// since it takes as many iterations as there are cards in the end
+fn count_cards_by_simulation(cards: &[ScratchCard]) -> Result<u64> {
+    let mut won_cards = cards.iter().map(|card| card.id).collect_vec();
+    let mut score = 0;
+
+    while let Some(card_id) = won_cards.pop() {
+        score += 1;
+
+        let winning_count = cards
+            .get(card_id.wrapping_sub(1))
+            .with_context(|| format!("scratchcard {card_id} not found"))?
+            .winning_count;
+
+        if winning_count == 0 {
+            continue;
+        }
+
+        for idx in 1..=winning_count {
+            let winning_card_id = card_id + idx;
+
+            if winning_card_id <= cards.len() {
+                won_cards.push(winning_card_id);
+            }
+        }
+    }
+
+    Ok(score)
+}
+
+fn parse_cards(input: &str) -> Result<Vec<ScratchCard>> {
+    Ok(parse_lines(input, |line| {
+        line.parse::<ScratchCard>()
+            .context("failed to parse scratchcard")
+    })?)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<ScratchCard>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_cards(input)
+    }
+
+    fn part1(cards: &Self::Input) -> Result<Option<Self::Answer1>> {
+        cards
+            .iter()
+            .try_fold(0u64, |total, card| {
+                total
+                    .checked_add(card.score()?)
+                    .context("the total score overflows an u64")
+            })
+            .map(Some)
+    }
+
+    fn part2(cards: &Self::Input) -> Result<Option<Self::Answer2>> {
+        Ok(Some(count_cards(cards)?))
+    }
+}
+
+/// Solves part 2 simulating every copy won, as a cross-check of [`Day4`]
+pub struct Day4Simulation;
+
+impl Solution for Day4Simulation {
+    type Input = Vec<ScratchCard>;
+    type Answer1 = Infallible;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_cards(input)
+    }
+
+    fn part2(cards: &Self::Input) -> Result<Option<Self::Answer2>> {
+        Ok(Some(count_cards_by_simulation(cards)?))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_common::{assert_answer, unlocked_input};
+
+    #[test]
+    fn test_example_card() {
+        let card: ScratchCard = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53"
+            .parse()
+            .unwrap();
+
+        assert_eq!(1, card.id);
+        assert_eq!(4, card.winning_count);
+        assert_eq!(8, card.score().unwrap());
+    }
+
+    #[test]
+    fn test_invalid_number() {
+        let err = Day4::parse("Card 1: 41 48 | 83 86\nCard 2: 13 3x | 61 30").unwrap_err();
+
+        assert_eq!(
+            "line 2, column 12: failed to parse scratchcard: failed to parse scratchcard numbers: invalid value \"3x\" (invalid digit found in string)",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_score_overflow() {
+        let numbers = (1..=65)
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let err = Day4::solve_part1(&format!("Card 1: {numbers} | {numbers}")).unwrap_err();
+
+        assert_eq!(
+            "the score of scratchcard 1 overflows an u64",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_total_score_overflow() {
+        let numbers = (1..=64)
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let input = format!("Card 1: {numbers} | {numbers}\nCard 2: {numbers} | {numbers}");
+
+        assert_eq!(
+            "the total score overflows an u64",
+            Day4::solve_part1(&input).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_solve_part1() {
+        let Some(input) = unlocked_input("input.txt") else {
+            return;
+        };
+
+        assert_answer(2023, 4, 1, Day4::solve_part1(&input).unwrap());
+    }
+
+    #[test]
+    fn test_demo_input_for_part2() {
+        assert_eq!(
+            Some(30),
+            Day4::solve_part2(include_str!("../demo-input.txt")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_demo_input_for_part2_by_simulation() {
+        assert_eq!(
+            Some(30),
+            Day4Simulation::solve_part2(include_str!("../demo-input.txt")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_count_cards_matches_simulation() {
+        // The last cards win copies of cards past the end of the table
+        let winning_counts = [3, 0, 2, 4, 1, 0, 0, 3, 2, 1, 4, 0, 2, 1, 3, 4, 2, 3];
+        let cards: Vec<ScratchCard> = winning_counts
+            .into_iter()
+            .enumerate()
+            .map(|(idx, winning_count)| ScratchCard {
+                id: idx + 1,
+                winning_count,
+            })
+            .collect();
+
+        assert_eq!(
+            count_cards_by_simulation(&cards).unwrap(),
+            count_cards(&cards).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_count_cards_overflow() {
+        // Each card doubles the copies of all the following ones
+        let cards: Vec<ScratchCard> = (1..=70)
+            .map(|id| ScratchCard {
+                id,
+                winning_count: 70,
+            })
+            .collect();
+
+        assert_eq!(
+            "the number of scratchcards overflows an u64",
+            count_cards(&cards).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_cards_out_of_order() {
+        let err = Day4::solve_part2("Card 1: 41 | 41\nCard 3: 13 | 61").unwrap_err();
+
+        assert_eq!(
+            "scratchcard 3 found in place of scratchcard 2",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        let Some(input) = unlocked_input("input.txt") else {
+            return;
+        };
+
+        assert_answer(2023, 4, 2, Day4::solve_part2(&input).unwrap());
+        assert_answer(2023, 4, 2, Day4Simulation::solve_part2(&input).unwrap());
+    }
+}
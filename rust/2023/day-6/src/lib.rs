@@ -0,0 +1,160 @@
+use anyhow::{ensure, Context, Result};
+use aoc_common::{parse_lines, parse_token, Solution};
+
+/// Values of the "Time:" and "Distance:" lines of the sheet, which part 1 reads
+/// as many races and part 2 as a single one
+#[derive(Debug)]
+pub struct Sheet {
+    times: String,
+    distances: String,
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Sheet;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut lines = parse_lines(input, |line| {
+            let (_, values) = line.split_once(':').context("failed to parse around ':'")?;
+
+            // Values are just checked here, since each part reads them differently
+            for value in values.split_ascii_whitespace() {
+                parse_token::<u64>(value)?;
+            }
+
+            Ok(values.to_owned())
+        })?
+        .into_iter();
+
+        let times = lines.next().context("missing the \"Time:\" line")?;
+        let distances = lines
+            .next_back()
+            .context("missing the \"Distance:\" line")?;
+
+        Ok(Sheet { times, distances })
+    }
+
+    fn part1(sheet: &Self::Input) -> Result<Option<Self::Answer1>> {
+        let times: Vec<u64> = sheet
+            .times
+            .split_ascii_whitespace()
+            .map(parse_token)
+            .collect::<Result<_>>()?;
+        let distances: Vec<u64> = sheet
+            .distances
+            .split_ascii_whitespace()
+            .map(parse_token)
+            .collect::<Result<_>>()?;
+
+        ensure!(
+            times.len() == distances.len(),
+            "the sheet has {} times but {} distances",
+            times.len(),
+            distances.len()
+        );
+
+        // Since the input of part 1 is very small and solutions all integers, just brute force each race
+        // A cleaner alternative solution would be a classic quadratic formula solver
+        times
+            .iter()
+            .zip(&distances)
+            .try_fold(1u64, |total, (&race_duration, &race_distance)| {
+                total
+                    .checked_mul(count_solutions(race_duration, race_distance))
+                    .context("the product of the solutions overflows an u64")
+            })
+            .map(Some)
+    }
+
+    fn part2(sheet: &Self::Input) -> Result<Option<Self::Answer2>> {
+        let race_duration: u64 = parse_token(&sheet.times.replace(' ', ""))?;
+        let race_distance: u64 = parse_token(&sheet.distances.replace(' ', ""))?;
+
+        // Actually also part 2 is still fast with the simple brute-force method
+        Ok(Some(count_solutions(race_duration, race_distance)))
+    }
+}
+
+/// Ways of holding the button that beat the record distance
+fn count_solutions(race_duration: u64, race_distance: u64) -> u64 {
+    // The distance is computed in u128, where the product of two u64 always fits
+    (1..race_duration)
+        .filter(|&i| i as u128 * (race_duration - i) as u128 > race_distance as u128)
+        .count() as u64
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_common::{assert_answer, unlocked_input};
+
+    #[test]
+    fn test_demo_input_for_part_1() {
+        assert_eq!(
+            Some(288),
+            Day6::solve_part1(include_str!("../demo-input.txt")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_demo_input_for_part_2() {
+        assert_eq!(
+            Some(71503),
+            Day6::solve_part2(include_str!("../demo-input.txt")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid_distance() {
+        let err = Day6::parse("Time:      7  15\nDistance:  9  4O").unwrap_err();
+
+        assert_eq!(
+            "line 2, column 15: invalid value \"4O\" (invalid digit found in string)",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_large_race() {
+        assert_eq!(
+            Some(599),
+            Day6::solve_part1("Time: 600\nDistance: 100").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_solutions_overflow() {
+        let err = Day6::solve_part1(&format!(
+            "Time: {}\nDistance: {}",
+            "3000 ".repeat(7),
+            "0 ".repeat(7)
+        ))
+        .unwrap_err();
+
+        assert_eq!(
+            "the product of the solutions overflows an u64",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_solve_part_1() {
+        let Some(input) = unlocked_input("input.txt") else {
+            return;
+        };
+
+        assert_answer(2023, 6, 1, Day6::solve_part1(&input).unwrap());
+    }
+
+    #[test]
+    fn test_solve_part_2() {
+        let Some(input) = unlocked_input("input.txt") else {
+            return;
+        };
+
+        assert_answer(2023, 6, 2, Day6::solve_part2(&input).unwrap());
+    }
+}
@@ -0,0 +1,303 @@
+use crate::{find_cycle, Cycle, Direction, Network, NodeId, Walk};
+use anyhow::{bail, Error, Result};
+use std::{collections::HashSet, fmt::Write, str::FromStr};
+
+/// Edge of the network, leaving a node in one direction
+type Edge = (NodeId, Direction);
+
+/// Text formats the network can be exported to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GraphFormat {
+    /// Graphviz DOT, e.g. `dot -Tsvg network.dot > network.svg`
+    Dot,
+    /// Mermaid flowchart, e.g. within a Markdown code block
+    Mermaid,
+}
+
+impl FromStr for GraphFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "dot" => Ok(GraphFormat::Dot),
+            "mermaid" => Ok(GraphFormat::Mermaid),
+            _ => bail!("unknown graph format {s:?}, expected \"dot\" or \"mermaid\""),
+        }
+    }
+}
+
+/// Walked edges to highlight on top of the exported network
+#[derive(Debug, Default)]
+pub struct Overlay {
+    /// Edges walked before reaching the goal, or before entering a loop
+    path: HashSet<Edge>,
+    /// Edges of the loops the walks end up in
+    cycles: HashSet<Edge>,
+}
+
+impl Overlay {
+    /// Path walked by part 1 from AAA, up to ZZZ or around the loop that
+    /// keeps it from getting there
+    pub fn camel_path(directions: &[Direction], network: &Network) -> Result<Overlay> {
+        let mut overlay = Overlay::default();
+        let goals = network.mark(Walk::CAMEL.is_goal);
+
+        for start in Walk::CAMEL.starts(network) {
+            let cycle = find_cycle(directions, network, start, &goals)?;
+            match cycle.goals.first() {
+                Some(&goal) => overlay
+                    .path
+                    .extend(edges(directions, network, start).take(goal as usize)),
+                None => overlay.add_cycle(directions, network, start, &cycle),
+            }
+        }
+
+        Ok(overlay)
+    }
+
+    /// Paths walked by the ghosts of part 2, each up to the loop it ends up in,
+    /// as found when solving the puzzle
+    pub fn ghost_cycles(directions: &[Direction], network: &Network) -> Result<Overlay> {
+        let mut overlay = Overlay::default();
+        let goals = network.mark(Walk::GHOSTS.is_goal);
+
+        for start in Walk::GHOSTS.starts(network) {
+            let cycle = find_cycle(directions, network, start, &goals)?;
+            overlay.add_cycle(directions, network, start, &cycle);
+        }
+
+        Ok(overlay)
+    }
+
+    /// Highlights the walk from `start` up to its loop as a path, then a lap
+    /// around the loop
+    fn add_cycle(
+        &mut self,
+        directions: &[Direction],
+        network: &Network,
+        start: NodeId,
+        cycle: &Cycle,
+    ) {
+        let mut edges = edges(directions, network, start);
+        self.path.extend(edges.by_ref().take(cycle.prefix as usize));
+        self.cycles.extend(edges.take(cycle.length as usize));
+    }
+
+    /// Style of the edge, if highlighted; loops win over paths
+    fn highlight(&self, edge: &Edge) -> Option<Highlight> {
+        if self.cycles.contains(edge) {
+            Some(Highlight::Cycle)
+        } else if self.path.contains(edge) {
+            Some(Highlight::Path)
+        } else {
+            None
+        }
+    }
+}
+
+/// Edges taken by a walk from `start`, following the directions forever
+fn edges<'a>(
+    directions: &'a [Direction],
+    network: &'a Network,
+    start: NodeId,
+) -> impl Iterator<Item = Edge> + 'a {
+    directions.iter().cycle().scan(start, |node, &direction| {
+        let edge = (*node, direction);
+        *node = network.next(*node, &direction);
+        Some(edge)
+    })
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Highlight {
+    Path,
+    Cycle,
+}
+
+impl Highlight {
+    fn color(&self) -> &'static str {
+        match self {
+            Highlight::Path => "blue",
+            Highlight::Cycle => "red",
+        }
+    }
+}
+
+/// Renders the network as a directed graph, with edges labelled by direction,
+/// start nodes (ending with A) in green and goal nodes (ending with Z) in red
+pub fn export_network(network: &Network, format: GraphFormat, overlay: &Overlay) -> String {
+    let edges: Vec<(Edge, NodeId)> = network
+        .nodes()
+        .flat_map(|node| [Direction::Left, Direction::Right].map(|dir| (node, dir)))
+        .map(|edge| (edge, network.next(edge.0, &edge.1)))
+        .collect();
+
+    match format {
+        GraphFormat::Dot => render_dot(network, &edges, overlay),
+        GraphFormat::Mermaid => render_mermaid(network, &edges, overlay),
+    }
+}
+
+fn label(direction: &Direction) -> &'static str {
+    match direction {
+        Direction::Left => "L",
+        Direction::Right => "R",
+    }
+}
+
+fn render_dot(network: &Network, edges: &[(Edge, NodeId)], overlay: &Overlay) -> String {
+    let mut dot = String::from("digraph network {\n");
+
+    for node in network.nodes() {
+        let name = network.name(node);
+        if (Walk::GHOSTS.is_start)(name) {
+            writeln!(dot, "    \"{name}\" [style=filled, fillcolor=palegreen];").unwrap();
+        } else if (Walk::GHOSTS.is_goal)(name) {
+            writeln!(dot, "    \"{name}\" [style=filled, fillcolor=lightcoral];").unwrap();
+        }
+    }
+
+    for (edge @ (from, direction), to) in edges {
+        let (from, to) = (network.name(*from), network.name(*to));
+        let style = match overlay.highlight(edge) {
+            Some(highlight) => format!(", color={}, penwidth=2", highlight.color()),
+            None => String::new(),
+        };
+        writeln!(
+            dot,
+            "    \"{from}\" -> \"{to}\" [label=\"{}\"{style}];",
+            label(direction)
+        )
+        .unwrap();
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+fn render_mermaid(network: &Network, edges: &[(Edge, NodeId)], overlay: &Overlay) -> String {
+    let mut mermaid = String::from("flowchart LR\n");
+
+    for node in network.nodes() {
+        writeln!(mermaid, "    n{node}[\"{}\"]", network.name(node)).unwrap();
+    }
+
+    for ((from, direction), to) in edges {
+        writeln!(mermaid, "    n{from} -->|{}| n{to}", label(direction)).unwrap();
+    }
+
+    writeln!(mermaid, "    classDef start fill:#9f9").unwrap();
+    writeln!(mermaid, "    classDef goal fill:#f99").unwrap();
+    for (class, predicate) in [
+        ("start", Walk::GHOSTS.is_start),
+        ("goal", Walk::GHOSTS.is_goal),
+    ] {
+        let nodes: Vec<String> = network
+            .nodes()
+            .filter(|&node| predicate(network.name(node)))
+            .map(|node| format!("n{node}"))
+            .collect();
+        if !nodes.is_empty() {
+            writeln!(mermaid, "    class {} {class}", nodes.join(",")).unwrap();
+        }
+    }
+
+    // Links are styled by their position, in order of declaration
+    for highlight in [Highlight::Path, Highlight::Cycle] {
+        let links: Vec<String> = edges
+            .iter()
+            .enumerate()
+            .filter(|(_, (edge, _))| overlay.highlight(edge) == Some(highlight))
+            .map(|(idx, _)| idx.to_string())
+            .collect();
+        if !links.is_empty() {
+            writeln!(
+                mermaid,
+                "    linkStyle {} stroke:{},stroke-width:3px",
+                links.join(","),
+                highlight.color()
+            )
+            .unwrap();
+        }
+    }
+
+    mermaid
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Day8;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_export_dot() {
+        let (directions, network) = Day8::parse(include_str!("../demo-input-2.txt")).unwrap();
+        let overlay = Overlay::camel_path(&directions, &network).unwrap();
+
+        assert_eq!(
+            r#"digraph network {
+    "AAA" [style=filled, fillcolor=palegreen];
+    "ZZZ" [style=filled, fillcolor=lightcoral];
+    "AAA" -> "BBB" [label="L", color=blue, penwidth=2];
+    "AAA" -> "BBB" [label="R", color=blue, penwidth=2];
+    "BBB" -> "AAA" [label="L", color=blue, penwidth=2];
+    "BBB" -> "ZZZ" [label="R", color=blue, penwidth=2];
+    "ZZZ" -> "ZZZ" [label="L"];
+    "ZZZ" -> "ZZZ" [label="R"];
+}
+"#,
+            export_network(&network, GraphFormat::Dot, &overlay)
+        );
+    }
+
+    #[test]
+    fn test_export_mermaid() {
+        let (directions, network) = Day8::parse(include_str!("../demo-input-part-2.txt")).unwrap();
+        let overlay = Overlay::ghost_cycles(&directions, &network).unwrap();
+
+        assert_eq!(
+            r#"flowchart LR
+    n0["11A"]
+    n1["11B"]
+    n2["11Z"]
+    n3["22A"]
+    n4["22B"]
+    n5["22C"]
+    n6["22Z"]
+    n7["XXX"]
+    n0 -->|L| n1
+    n0 -->|R| n7
+    n1 -->|L| n7
+    n1 -->|R| n2
+    n2 -->|L| n1
+    n2 -->|R| n7
+    n3 -->|L| n4
+    n3 -->|R| n7
+    n4 -->|L| n5
+    n4 -->|R| n5
+    n5 -->|L| n6
+    n5 -->|R| n6
+    n6 -->|L| n4
+    n6 -->|R| n4
+    n7 -->|L| n7
+    n7 -->|R| n7
+    classDef start fill:#9f9
+    classDef goal fill:#f99
+    class n0,n3 start
+    class n2,n6 goal
+    linkStyle 0,6 stroke:blue,stroke-width:3px
+    linkStyle 3,4,8,9,10,11,12,13 stroke:red,stroke-width:3px
+"#,
+            export_network(&network, GraphFormat::Mermaid, &overlay)
+        );
+    }
+
+    #[test]
+    fn test_unknown_format() {
+        assert_eq!(
+            "unknown graph format \"svg\", expected \"dot\" or \"mermaid\"",
+            "svg".parse::<GraphFormat>().unwrap_err().to_string()
+        );
+    }
+}
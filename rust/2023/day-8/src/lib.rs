@@ -0,0 +1,630 @@
+mod export;
+
+pub use export::{export_network, GraphFormat, Overlay};
+
+use anyhow::{bail, ensure, Context, Result};
+use aoc_common::{math::crt, ParseError, Solution, TokenError};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{self, Display},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+/// Index of a node within its [`Network`]
+pub type NodeId = u32;
+
+/// Network of nodes, with their names interned to indices: each step of a
+/// walk is a lookup in an array.
+#[derive(Debug)]
+pub struct Network {
+    names: Vec<String>,
+    /// Node reached going left from each node
+    left: Vec<NodeId>,
+    /// Node reached going right from each node
+    right: Vec<NodeId>,
+}
+
+impl Network {
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = NodeId> {
+        0..self.len() as NodeId
+    }
+
+    pub fn name(&self, node: NodeId) -> &str {
+        &self.names[node as usize]
+    }
+
+    /// Node reached from `node` following `direction`
+    pub fn next(&self, node: NodeId, direction: &Direction) -> NodeId {
+        match direction {
+            Direction::Left => self.left[node as usize],
+            Direction::Right => self.right[node as usize],
+        }
+    }
+
+    /// Whether each node satisfies the predicate, by index
+    fn mark(&self, predicate: fn(&str) -> bool) -> Vec<bool> {
+        self.names.iter().map(|name| predicate(name)).collect()
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = (Vec<Direction>, Network);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1((directions, network): &Self::Input) -> Result<Option<Self::Answer1>> {
+        Ok(Some(Walk::CAMEL.steps(directions, network)?))
+    }
+
+    fn part2((directions, network): &Self::Input) -> Result<Option<Self::Answer2>> {
+        let goals = network.mark(Walk::GHOSTS.is_goal);
+        let cycles = Walk::GHOSTS
+            .starts(network)
+            .into_iter()
+            .map(|node| find_cycle(directions, network, node, &goals))
+            .collect::<Result<Vec<_>>>()?;
+        ensure!(!cycles.is_empty(), "there are no start nodes");
+
+        first_common_goal(&cycles)?
+            .context("the ghosts are never all on goal nodes at once")
+            .map(Some)
+    }
+}
+
+/// Which nodes a walk through the network starts from, and which ones it
+/// heads to
+#[derive(Clone, Copy)]
+pub struct Walk {
+    pub is_start: fn(&str) -> bool,
+    pub is_goal: fn(&str) -> bool,
+}
+
+impl Walk {
+    /// Walk of part 1, from AAA to ZZZ
+    pub const CAMEL: Walk = Walk {
+        is_start: |location| location == "AAA",
+        is_goal: |location| location == "ZZZ",
+    };
+
+    /// Walk of part 2, from every node ending with A to any node ending with Z
+    pub const GHOSTS: Walk = Walk {
+        is_start: |location| location.ends_with('A'),
+        is_goal: |location| location.ends_with('Z'),
+    };
+
+    /// Start nodes, sorted by name
+    pub fn starts(&self, network: &Network) -> Vec<NodeId> {
+        let mut starts: Vec<_> = network
+            .nodes()
+            .filter(|&node| (self.is_start)(network.name(node)))
+            .collect();
+        starts.sort_unstable_by_key(|&node| network.name(node));
+        starts
+    }
+
+    /// Steps from the only start node to the first goal node.
+    ///
+    /// Fails with an [`UnreachableError`] when the walk gets back to a state,
+    /// i.e. a node together with the position in the directions, it was
+    /// already in before reaching any goal: from then on, it loops forever.
+    pub fn steps(&self, directions: &[Direction], network: &Network) -> Result<u64> {
+        let start = match self.starts(network)[..] {
+            [start] => start,
+            ref starts => bail!("expected a single start node, found {}", starts.len()),
+        };
+        let goals = network.mark(self.is_goal);
+
+        let mut visited = States::new(network);
+        let mut current_node = start;
+
+        for (step, (idx, direction)) in directions.iter().enumerate().cycle().enumerate() {
+            if goals[current_node as usize] {
+                return Ok(step as u64);
+            }
+
+            if let Some(first_visit) = visited.visit(current_node, idx, step as u64) {
+                // Walks the loop once more, to report its nodes
+                let cycle = directions
+                    .iter()
+                    .cycle()
+                    .take(step - first_visit as usize)
+                    .scan(current_node, |node, direction| {
+                        *node = network.next(*node, direction);
+                        Some(*node)
+                    });
+                let cycle = [current_node]
+                    .into_iter()
+                    .chain(cycle)
+                    .map(|node| network.name(node).to_owned())
+                    .collect();
+
+                return Err(UnreachableError {
+                    start: network.name(start).to_owned(),
+                    cycle,
+                }
+                .into());
+            }
+
+            current_node = network.next(current_node, direction);
+        }
+
+        bail!("there are no directions to follow")
+    }
+}
+
+/// Step at which a walk first visited each state at the start of the
+/// directions, i.e. each node with the directions starting over.
+///
+/// Since the position in the directions goes around with each step, a walk
+/// can only get back to a state after a multiple of the directions length:
+/// every loop passes through a state at the start of the directions, and
+/// tracking those is enough to detect it.
+struct States {
+    first_visits: Vec<u64>,
+}
+
+impl States {
+    const NOT_VISITED: u64 = u64::MAX;
+
+    fn new(network: &Network) -> States {
+        States {
+            first_visits: vec![Self::NOT_VISITED; network.len()],
+        }
+    }
+
+    /// Marks the state as visited at `step`, returning the step of the
+    /// previous visit if there was one
+    fn visit(&mut self, node: NodeId, idx: usize, step: u64) -> Option<u64> {
+        if idx != 0 {
+            return None;
+        }
+
+        let first_visit = &mut self.first_visits[node as usize];
+        if *first_visit != Self::NOT_VISITED {
+            return Some(*first_visit);
+        }
+
+        *first_visit = step;
+        None
+    }
+}
+
+/// Error of a walk that never reaches a goal node, since it loops
+#[derive(Debug, PartialEq)]
+pub struct UnreachableError {
+    pub start: String,
+    /// Nodes of the loop, starting and ending with the same node
+    pub cycle: Vec<String>,
+}
+
+impl Display for UnreachableError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Long loops are cut, to keep the message on a single readable line
+        const SHOWN_NODES: usize = 10;
+
+        write!(
+            f,
+            "no goal is reachable from {}, the walk loops every {} steps through ",
+            self.start,
+            self.cycle.len() - 1
+        )?;
+        if self.cycle.len() > SHOWN_NODES {
+            write!(f, "{} -> ...", self.cycle[..SHOWN_NODES].join(" -> "))
+        } else {
+            write!(f, "{}", self.cycle.join(" -> "))
+        }
+    }
+}
+
+impl Error for UnreachableError {}
+
+/// Walk of a ghost up to the first state it repeats, where a state is the
+/// node together with the position in the directions: from then on, the walk
+/// loops forever. Only states at the start of the directions are tracked, see
+/// [`States`].
+#[derive(Debug, PartialEq)]
+struct Cycle {
+    /// Steps after which the walk loops; at most one pass over the directions
+    /// after it actually enters the loop
+    prefix: u64,
+    /// Steps of a lap around the loop
+    length: u64,
+    /// Steps at which the ghost stands on a goal node, before the end of the
+    /// first lap; the ones in the loop repeat every `length` steps
+    goals: Vec<u64>,
+}
+
+impl Cycle {
+    fn is_goal(&self, step: u64) -> bool {
+        if step < self.prefix {
+            return self.goals.contains(&step);
+        }
+
+        self.goals
+            .iter()
+            .any(|&goal| goal >= self.prefix && goal % self.length == step % self.length)
+    }
+}
+
+fn find_cycle(
+    directions: &[Direction],
+    network: &Network,
+    start: NodeId,
+    goals: &[bool],
+) -> Result<Cycle> {
+    let mut visited = States::new(network);
+    let mut goal_steps = Vec::new();
+    let mut current_node = start;
+
+    for (step, (idx, direction)) in directions.iter().enumerate().cycle().enumerate() {
+        let step = step as u64;
+
+        if let Some(prefix) = visited.visit(current_node, idx, step) {
+            return Ok(Cycle {
+                prefix,
+                length: step - prefix,
+                goals: goal_steps,
+            });
+        }
+
+        if goals[current_node as usize] {
+            goal_steps.push(step);
+        }
+
+        current_node = network.next(current_node, direction);
+    }
+
+    bail!("there are no directions to follow")
+}
+
+/// First step at which all the ghosts stand on a goal node at once.
+///
+/// Steps before the longest prefix are checked one by one against every
+/// ghost; past it, every ghost is in its loop, so the goals solve a system of
+/// congruences, one for each ghost and goal in its loop.
+fn first_common_goal(cycles: &[Cycle]) -> Result<Option<u64>> {
+    let longest_prefix = cycles.iter().map(|cycle| cycle.prefix).max().unwrap_or(0);
+
+    if let Some(step) =
+        (0..longest_prefix).find(|&step| cycles.iter().all(|cycle| cycle.is_goal(step)))
+    {
+        return Ok(Some(step));
+    }
+
+    // Solutions of the congruences of the ghosts seen so far, as (residue, modulus)
+    let mut solutions: Vec<(i128, i128)> = vec![(0, 1)];
+    for cycle in cycles {
+        let mut combined = Vec::new();
+        for &solution in &solutions {
+            for &goal in cycle.goals.iter().filter(|&&goal| goal >= cycle.prefix) {
+                if let Some(solution) = crt([solution, (goal as i128, cycle.length as i128)])? {
+                    combined.push(solution);
+                }
+            }
+        }
+        combined.sort_unstable();
+        combined.dedup();
+        solutions = combined;
+    }
+
+    // Smallest solution that isn't before the longest prefix
+    let longest_prefix = longest_prefix as i128;
+    solutions
+        .into_iter()
+        .map(|(residue, modulus)| {
+            let laps = ((longest_prefix - residue).max(0) + modulus - 1) / modulus;
+            residue + laps * modulus
+        })
+        .min()
+        .map(|step| u64::try_from(step).context("the number of steps overflows an u64"))
+        .transpose()
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Direction>, Network)> {
+    let first_line = input
+        .lines()
+        .next()
+        .context("missing the directions line")?;
+    let directions: Vec<Direction> = first_line
+        .char_indices()
+        .map(|(pos, x)| match x {
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            _ => {
+                Err(TokenError::new(&first_line[pos..], format!("unknown direction {x:?}")).into())
+            }
+        })
+        .collect::<Result<_>>()
+        .map_err(|err| ParseError::new(1, first_line, err))?;
+
+    let nodes: Vec<(usize, &str, [&str; 3])> = input
+        .lines()
+        .enumerate()
+        .skip(2)
+        .map(|(idx, x)| match parse_node(x) {
+            Ok(node) => Ok((idx + 1, x, node)),
+            Err(err) => Err(ParseError::new(idx + 1, x, err)),
+        })
+        .collect::<Result<_, _>>()?;
+    ensure!(
+        nodes.len() <= NodeId::MAX as usize,
+        "too many nodes in the network"
+    );
+
+    // Interns the names of the nodes, in order of definition
+    let mut ids: HashMap<&str, NodeId> = HashMap::new();
+    for &(line_number, line, [location, _, _]) in &nodes {
+        if ids.insert(location, ids.len() as NodeId).is_some() {
+            let err = TokenError::new(location, format!("node {location} is defined twice"));
+            return Err(ParseError::new(line_number, line, err.into()).into());
+        }
+    }
+
+    let resolve = |line_number: usize, line: &str, location: &str| {
+        ids.get(location).copied().ok_or_else(|| {
+            let err = TokenError::new(
+                location,
+                format!("node {location} not found in the network"),
+            );
+            ParseError::new(line_number, line, err.into())
+        })
+    };
+
+    let mut network = Network {
+        names: Vec::with_capacity(nodes.len()),
+        left: Vec::with_capacity(nodes.len()),
+        right: Vec::with_capacity(nodes.len()),
+    };
+    for &(line_number, line, [location, left, right]) in &nodes {
+        network.names.push(location.to_owned());
+        network.left.push(resolve(line_number, line, left)?);
+        network.right.push(resolve(line_number, line, right)?);
+    }
+
+    Ok((directions, network))
+}
+
+/// Splits a node into its location and the locations to its left and right
+fn parse_node(line: &str) -> Result<[&str; 3]> {
+    let (location, targets) = line
+        .split_once(" = ")
+        .context("failed to parse around '='")?;
+    let targets = targets.trim_start_matches('(').trim_end_matches(')');
+    let (left, right) = targets
+        .split_once(", ")
+        .context("failed to parse around ','")?;
+
+    Ok([location, left, right])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_common::{assert_answer, unlocked_input};
+
+    #[test]
+    fn test_demo_input_for_part_1() {
+        assert_eq!(
+            Some(2),
+            Day8::solve_part1(include_str!("../demo-input.txt")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_demo_input_for_part_1_bis() {
+        assert_eq!(
+            Some(6),
+            Day8::solve_part1(include_str!("../demo-input-2.txt")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_demo_input_for_part_2() {
+        assert_eq!(
+            Some(6),
+            Day8::solve_part2(include_str!("../demo-input-part-2.txt")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let (directions, network) = Day8::parse(include_str!("../demo-input-part-2.txt")).unwrap();
+        let goals = network.mark(Walk::GHOSTS.is_goal);
+        let cycle = |start: &str| {
+            let start = network
+                .nodes()
+                .find(|&node| network.name(node) == start)
+                .unwrap();
+            find_cycle(&directions, &network, start, &goals).unwrap()
+        };
+
+        assert_eq!(
+            Cycle {
+                prefix: 2,
+                length: 2,
+                goals: vec![2]
+            },
+            cycle("11A")
+        );
+        assert_eq!(
+            Cycle {
+                prefix: 2,
+                length: 6,
+                goals: vec![3, 6]
+            },
+            cycle("22A")
+        );
+    }
+
+    #[test]
+    fn test_first_common_goal_off_cycle() {
+        // Goals that aren't at multiples of the cycle length, and one only
+        // reached before the ghost enters its loop
+        let cycles = [
+            Cycle {
+                prefix: 3,
+                length: 4,
+                goals: vec![1, 5],
+            },
+            Cycle {
+                prefix: 0,
+                length: 6,
+                goals: vec![1, 3],
+            },
+        ];
+
+        assert_eq!(Some(1), first_common_goal(&cycles).unwrap());
+
+        let cycles = [
+            Cycle {
+                prefix: 3,
+                length: 4,
+                goals: vec![5],
+            },
+            Cycle {
+                prefix: 0,
+                length: 6,
+                goals: vec![1, 3],
+            },
+        ];
+
+        assert_eq!(Some(9), first_common_goal(&cycles).unwrap());
+    }
+
+    #[test]
+    fn test_no_common_goal() {
+        let cycles = [
+            Cycle {
+                prefix: 0,
+                length: 2,
+                goals: vec![0],
+            },
+            Cycle {
+                prefix: 0,
+                length: 4,
+                goals: vec![1],
+            },
+        ];
+
+        assert_eq!(None, first_common_goal(&cycles).unwrap());
+    }
+
+    #[test]
+    fn test_long_walk() {
+        // More passes over the directions than any fixed cap would allow
+        let nodes: Vec<String> = (0..100).map(|idx| format!("N{idx:02}")).collect();
+        let mut input = format!("L\n\nAAA = ({0}, {0})\n", nodes[0]);
+        for (node, next) in nodes.iter().zip(nodes.iter().skip(1)) {
+            input.push_str(&format!("{node} = ({next}, {next})\n"));
+        }
+        input.push_str(&format!("{0} = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)", nodes[99]));
+
+        assert_eq!(Some(101), Day8::solve_part1(&input).unwrap());
+    }
+
+    #[test]
+    fn test_unreachable_goal() {
+        let err = Day8::solve_part1(
+            "LR\n\nAAA = (BBB, CCC)\nBBB = (ZZZ, AAA)\nCCC = (CCC, CCC)\nZZZ = (ZZZ, ZZZ)",
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            Some(&UnreachableError {
+                start: "AAA".to_owned(),
+                cycle: vec!["AAA".to_owned(), "BBB".to_owned(), "AAA".to_owned()],
+            }),
+            err.downcast_ref::<UnreachableError>()
+        );
+        assert_eq!(
+            "no goal is reachable from AAA, the walk loops every 2 steps through AAA -> BBB -> AAA",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_custom_walk() {
+        let (directions, network) = Day8::parse(include_str!("../demo-input-2.txt")).unwrap();
+        let walk = Walk {
+            is_start: |location| location == "BBB",
+            is_goal: |location| location.starts_with('Z'),
+        };
+
+        assert_eq!(3, walk.steps(&directions, &network).unwrap());
+
+        let (directions, network) = Day8::parse(include_str!("../demo-input-part-2.txt")).unwrap();
+        assert_eq!(
+            "expected a single start node, found 2",
+            Walk::GHOSTS
+                .steps(&directions, &network)
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_unknown_direction() {
+        let err = Day8::parse("LRX\n\nAAA = (BBB, BBB)").unwrap_err();
+
+        assert_eq!("line 1, column 3: unknown direction 'X'", err.to_string());
+    }
+
+    #[test]
+    fn test_missing_node() {
+        let err = Day8::solve_part1("L\n\nAAA = (BBB, BBB)").unwrap_err();
+
+        assert_eq!(
+            "line 3, column 8: node BBB not found in the network",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_duplicate_node() {
+        let err =
+            Day8::parse("L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nAAA = (AAA, AAA)").unwrap_err();
+
+        assert_eq!(
+            "line 5, column 1: node AAA is defined twice",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_solve_part_1() {
+        let Some(input) = unlocked_input("input.txt") else {
+            return;
+        };
+
+        assert_answer(2023, 8, 1, Day8::solve_part1(&input).unwrap());
+    }
+
+    #[test]
+    fn test_solve_part_2() {
+        let Some(input) = unlocked_input("input.txt") else {
+            return;
+        };
+
+        assert_answer(2023, 8, 2, Day8::solve_part2(&input).unwrap());
+    }
+}
@@ -0,0 +1,242 @@
+use crate::{
+    output,
+    registry::{Entry, ENTRIES},
+};
+use anyhow::{Context, Result};
+use aoc_common::LockedInputError;
+use serde::{Deserialize, Serialize};
+use std::{fs, path::Path, time::Duration};
+
+/// Statistics of the timings of a stage (parsing or a part) of a day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Measure {
+    year: u16,
+    day: u8,
+    variant: Option<String>,
+    /// Folder of the day crate, which tells apart the crates solving the same
+    /// day without a variant
+    dir: String,
+    /// "parse", "part 1" or "part 2"
+    stage: String,
+    mean_ns: f64,
+    median_ns: f64,
+    stddev_ns: f64,
+}
+
+impl Measure {
+    fn new(entry: &Entry, stage: &str, samples: &[Duration]) -> Self {
+        let (mean_ns, median_ns, stddev_ns) = statistics(samples);
+
+        Measure {
+            year: entry.year,
+            day: entry.day,
+            variant: entry.variant.map(String::from),
+            dir: String::from(entry.dir),
+            stage: String::from(stage),
+            mean_ns,
+            median_ns,
+            stddev_ns,
+        }
+    }
+
+    fn same_stage(&self, other: &Measure) -> bool {
+        self.year == other.year
+            && self.day == other.day
+            && self.variant == other.variant
+            && self.dir == other.dir
+            && self.stage == other.stage
+    }
+}
+
+/// Mean, median and standard deviation of the samples, in nanoseconds
+fn statistics(samples: &[Duration]) -> (f64, f64, f64) {
+    let mut nanos: Vec<f64> = samples.iter().map(|x| x.as_nanos() as f64).collect();
+    nanos.sort_by(f64::total_cmp);
+
+    let count = nanos.len() as f64;
+    let mean = nanos.iter().sum::<f64>() / count;
+    let median = match nanos.len() % 2 {
+        0 => (nanos[nanos.len() / 2 - 1] + nanos[nanos.len() / 2]) / 2.0,
+        _ => nanos[nanos.len() / 2],
+    };
+    let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / count;
+
+    (mean, median, variance.sqrt())
+}
+
+/// Runs the selected days the given number of times, timing the parsing of the
+/// input separately from each part, and optionally saves or compares a baseline.
+pub fn bench(
+    year: Option<u16>,
+    day: Option<u8>,
+    iterations: u32,
+    save_baseline: Option<&Path>,
+    baseline: Option<&Path>,
+) -> Result<()> {
+    let baseline: Vec<Measure> = match baseline {
+        Some(path) => serde_json::from_str(
+            &fs::read_to_string(path)
+                .with_context(|| format!("failed to read the baseline from {}", path.display()))?,
+        )
+        .with_context(|| format!("failed to parse the baseline from {}", path.display()))?,
+        None => Vec::new(),
+    };
+
+    let mut measures = Vec::new();
+
+    for entry in ENTRIES.iter().filter(|entry| {
+        year.is_none_or(|year| entry.year == year) && day.is_none_or(|day| entry.day == day)
+    }) {
+        let input = match entry.default_input().read_to_string() {
+            Ok(input) => input,
+            Err(err)
+                if err
+                    .get_ref()
+                    .is_some_and(|inner| inner.is::<LockedInputError>()) =>
+            {
+                eprintln!("skipped {} day {}: {err}", entry.year, entry.day);
+                continue;
+            }
+            Err(err) => {
+                return Err(err).with_context(|| {
+                    format!(
+                        "failed to read the input of {} day {}",
+                        entry.year, entry.day
+                    )
+                })
+            }
+        };
+
+        measures.append(&mut bench_entry(entry, &input, iterations)?);
+    }
+
+    print!("{}", format_table(&measures, &baseline));
+
+    if let Some(path) = save_baseline {
+        fs::write(path, serde_json::to_string_pretty(&measures)?)
+            .with_context(|| format!("failed to save the baseline into {}", path.display()))?;
+    }
+
+    Ok(())
+}
+
+fn bench_entry(entry: &Entry, input: &str, iterations: u32) -> Result<Vec<Measure>> {
+    let mut parse_samples = Vec::new();
+    let mut part_samples: [Vec<Duration>; 2] = Default::default();
+
+    for _ in 0..iterations {
+        let run = (entry.solve)(input, &[1, 2])
+            .with_context(|| format!("failed to solve {} day {}", entry.year, entry.day))?;
+
+        parse_samples.push(run.parse_time);
+        for answer in run.answers {
+            answer.answer.with_context(|| {
+                format!(
+                    "failed to solve {} day {} part {}",
+                    entry.year, entry.day, answer.part
+                )
+            })?;
+            part_samples[answer.part as usize - 1].push(answer.elapsed);
+        }
+    }
+
+    let mut measures = vec![Measure::new(entry, "parse", &parse_samples)];
+    for (idx, samples) in part_samples.iter().enumerate() {
+        // Parts that the day doesn't solve have no samples
+        if !samples.is_empty() {
+            measures.push(Measure::new(entry, &format!("part {}", idx + 1), samples));
+        }
+    }
+
+    Ok(measures)
+}
+
+fn format_table(measures: &[Measure], baseline: &[Measure]) -> String {
+    let format_ns = |ns: f64| format!("{:.2?}", Duration::from_secs_f64(ns / 1e9));
+
+    let header = [
+        "Year", "Day", "Variant", "Folder", "Stage", "Mean", "Median", "Std dev", "Baseline",
+        "Change",
+    ];
+    let rows: Vec<[String; 10]> = measures
+        .iter()
+        .map(|measure| {
+            let previous = baseline.iter().find(|x| x.same_stage(measure));
+            let (previous_median, change) = match previous {
+                // A relative change from nothing has no meaning
+                Some(previous) if previous.median_ns == 0.0 => {
+                    (format_ns(previous.median_ns), String::from("-"))
+                }
+                Some(previous) => (
+                    format_ns(previous.median_ns),
+                    format!(
+                        "{:+.1}%",
+                        (measure.median_ns - previous.median_ns) / previous.median_ns * 100.0
+                    ),
+                ),
+                None => (String::from("-"), String::from("-")),
+            };
+
+            [
+                measure.year.to_string(),
+                measure.day.to_string(),
+                String::from(measure.variant.as_deref().unwrap_or("-")),
+                measure.dir.clone(),
+                measure.stage.clone(),
+                format_ns(measure.mean_ns),
+                format_ns(measure.median_ns),
+                format_ns(measure.stddev_ns),
+                previous_median,
+                change,
+            ]
+        })
+        .collect();
+
+    output::format_table(header, &rows)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_statistics() {
+        let samples = [2, 4, 4, 4, 5, 5, 7, 9].map(Duration::from_nanos);
+
+        assert_eq!((5.0, 4.5, 2.0), statistics(&samples));
+        assert_eq!((3.0, 3.0, 0.0), statistics(&[Duration::from_nanos(3)]));
+    }
+
+    fn measure(dir: &str, median_ns: f64) -> Measure {
+        Measure {
+            year: 2022,
+            day: 2,
+            variant: None,
+            dir: String::from(dir),
+            stage: String::from("part 1"),
+            mean_ns: median_ns,
+            median_ns,
+            stddev_ns: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_same_stage_in_another_folder() {
+        let day_2 = measure("2022/day-2", 100.0);
+
+        assert!(day_2.same_stage(&measure("2022/day-2", 200.0)));
+        assert!(!day_2.same_stage(&measure("2022/day-2-bis", 100.0)));
+    }
+
+    #[test]
+    fn test_change_from_baseline() {
+        let measures = [measure("2022/day-2", 150.0)];
+
+        let table = format_table(&measures, &[measure("2022/day-2", 100.0)]);
+        assert!(table.lines().nth(1).unwrap().ends_with("+50.0%"));
+
+        let table = format_table(&measures, &[measure("2022/day-2", 0.0)]);
+        assert!(table.lines().nth(1).unwrap().ends_with(" -"));
+        assert!(!table.contains("inf") && !table.contains("NaN"));
+    }
+}
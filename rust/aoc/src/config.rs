@@ -0,0 +1,57 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::{env, fs, path::PathBuf};
+
+/// Environment variable pointing to the configuration file of the runner
+pub const CONFIG_ENV: &str = "AOC_CONFIG";
+
+/// Environment variable overriding the session token of the configuration file
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// Settings of the current user, read from `~/.config/aoc/config.toml` (or
+/// from the file set by [`CONFIG_ENV`]), e.g.:
+///
+/// ```toml
+/// session = "53616c746564..."
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Value of the "session" cookie of a logged in adventofcode.com account
+    pub session: Option<String>,
+    /// Address of the website, e.g. of a local mock server
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    pub fn path() -> Option<PathBuf> {
+        env::var_os(CONFIG_ENV).map(PathBuf::from).or_else(|| {
+            env::var_os("HOME")
+                .or_else(|| env::var_os("USERPROFILE"))
+                .map(|home| PathBuf::from(home).join(".config/aoc/config.toml"))
+        })
+    }
+
+    /// Loads the configuration file, if any, then applies the overrides of the
+    /// environment.
+    pub fn load() -> Result<Self> {
+        let mut config = match Self::path().filter(|path| path.exists()) {
+            Some(path) => {
+                let text = fs::read_to_string(&path).with_context(|| {
+                    format!("failed to read the config from {}", path.display())
+                })?;
+
+                toml::from_str(&text).with_context(|| {
+                    format!("failed to parse the config from {}", path.display())
+                })?
+            }
+            None => Config::default(),
+        };
+
+        if let Ok(session) = env::var(SESSION_ENV) {
+            config.session = Some(session);
+        }
+
+        Ok(config)
+    }
+}
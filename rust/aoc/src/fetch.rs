@@ -0,0 +1,335 @@
+use crate::{
+    config::{Config, SESSION_ENV},
+    http::{HttpClient, UreqClient},
+    registry,
+};
+use anyhow::{bail, Context, Result};
+use aoc_common::{InputSource, LockedInputError, DEFAULT_INPUT};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    /// A readable input was already there, so nothing was downloaded
+    Cached,
+    Downloaded,
+}
+
+/// Downloads the personal input of a puzzle into the folder of each crate that
+/// solves it, unless it's already there.
+pub fn fetch(year: u16, day: u8, force: bool) -> Result<()> {
+    let config = Config::load()?;
+    let client = UreqClient::new();
+
+    let paths: Vec<PathBuf> = day_dirs(year, day)?
+        .into_iter()
+        .map(|dir| dir.join(DEFAULT_INPUT))
+        .collect();
+
+    let fetched = fetch_input(&client, &config, year, day, &paths, force)?;
+    for (path, fetched) in paths.iter().zip(fetched) {
+        match fetched {
+            Fetched::Cached => println!("Input already cached in {}", path.display()),
+            Fetched::Downloaded => println!("Input saved into {}", path.display()),
+        }
+    }
+
+    Ok(())
+}
+
+/// Folders of the crates solving the puzzle, or the folder of the day when it
+/// has no solution registered yet
+fn day_dirs(year: u16, day: u8) -> Result<Vec<PathBuf>> {
+    let mut dirs: Vec<PathBuf> = registry::find(year, day)
+        .iter()
+        .map(|entry| registry::workspace_dir().join(entry.dir))
+        .collect();
+    dirs.dedup();
+
+    if dirs.is_empty() {
+        let dir = registry::workspace_dir().join(format!("{year}/day-{day}"));
+        if !dir.is_dir() {
+            bail!("no folder for {year} day {day}, create the day crate first");
+        }
+
+        dirs.push(dir);
+    }
+
+    Ok(dirs)
+}
+
+/// Saves the input into each of the paths where it isn't cached yet, downloading
+/// it at most once.
+pub fn fetch_input(
+    client: &impl HttpClient,
+    config: &Config,
+    year: u16,
+    day: u8,
+    paths: &[PathBuf],
+    force: bool,
+) -> Result<Vec<Fetched>> {
+    let mut fetched = Vec::new();
+    for path in paths {
+        if !force && is_cached(path)? {
+            fetched.push(Fetched::Cached);
+        } else {
+            fetched.push(Fetched::Downloaded);
+        }
+    }
+
+    if !fetched.contains(&Fetched::Downloaded) {
+        return Ok(fetched);
+    }
+
+    let body = download_input(client, config, year, day)?;
+    for (path, _) in paths
+        .iter()
+        .zip(&fetched)
+        .filter(|(_, fetched)| **fetched == Fetched::Downloaded)
+    {
+        fs::write(path, &body)
+            .with_context(|| format!("failed to save the input into {}", path.display()))?;
+    }
+
+    Ok(fetched)
+}
+
+/// Whether a readable input is already at `path`.
+///
+/// Inputs still encrypted by git-crypt belong to someone else, so they're
+/// replaced, unless git tracks them: the git-crypt filter is inactive while the
+/// repository is locked, so the plain text input would then get committed.
+fn is_cached(path: &Path) -> Result<bool> {
+    match InputSource::from(path.to_owned()).read_to_string() {
+        Ok(_) => Ok(true),
+        Err(err)
+            if err
+                .get_ref()
+                .is_some_and(|inner| inner.is::<LockedInputError>()) =>
+        {
+            if is_tracked(path) {
+                bail!(
+                    "{} is encrypted by git-crypt and tracked by git, replacing it while the \
+                     repository is locked would commit the input in plain text; run \
+                     `git-crypt unlock` first, or pass --force to replace it anyway",
+                    path.display()
+                );
+            }
+            Ok(false)
+        }
+        Err(_) => Ok(false),
+    }
+}
+
+/// Whether the file is in the git index; `false` outside of a git repository
+fn is_tracked(path: &Path) -> bool {
+    let (Some(dir), Some(name)) = (path.parent(), path.file_name()) else {
+        return false;
+    };
+
+    Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["ls-files", "--error-unmatch", "--"])
+        .arg(name)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+fn download_input(client: &impl HttpClient, config: &Config, year: u16, day: u8) -> Result<String> {
+    let session = config.session.as_deref().with_context(|| {
+        let path = Config::path().map_or_else(
+            || String::from("the config file"),
+            |path| path.display().to_string(),
+        );
+        format!("no session token configured, set `session` in {path} or {SESSION_ENV}")
+    })?;
+    let base_url = config.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL);
+    let url = format!("{}/{year}/day/{day}/input", base_url.trim_end_matches('/'));
+
+    let response = client
+        .get(&url, &[("Cookie", &format!("session={session}"))])
+        .with_context(|| format!("failed to download the input of {year} day {day}"))?;
+
+    match response.status {
+        200 => Ok(response.body),
+        404 => bail!("the input of {year} day {day} is not available yet"),
+        400 | 500 => bail!(
+            "the session token was rejected (HTTP {}), it may have expired",
+            response.status
+        ),
+        status => bail!("failed to download {url} (HTTP {status})"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::http::Response;
+    use std::{cell::RefCell, env, slice};
+
+    /// Client replying with a canned response, recording the requests it gets
+    struct MockClient {
+        response: Response,
+        requests: RefCell<Vec<(String, String)>>,
+    }
+
+    impl MockClient {
+        fn new(status: u16, body: &str) -> Self {
+            MockClient {
+                response: Response {
+                    status,
+                    body: String::from(body),
+                },
+                requests: RefCell::new(Vec::new()),
+            }
+        }
+    }
+
+    impl HttpClient for MockClient {
+        fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Response> {
+            let cookie = headers
+                .iter()
+                .find(|(name, _)| *name == "Cookie")
+                .map_or_else(String::new, |(_, value)| value.to_string());
+            self.requests.borrow_mut().push((url.to_owned(), cookie));
+
+            Ok(self.response.clone())
+        }
+    }
+
+    fn config() -> Config {
+        Config {
+            session: Some(String::from("abc")),
+            base_url: Some(String::from("http://localhost:8080/")),
+        }
+    }
+
+    fn temp_input(name: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("aoc-fetch-{name}.txt"));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn test_download_and_cache() {
+        let path = temp_input("download");
+        let client = MockClient::new(200, "1 2 3\n");
+
+        assert_eq!(
+            vec![Fetched::Downloaded],
+            fetch_input(&client, &config(), 2023, 6, slice::from_ref(&path), false).unwrap()
+        );
+        assert_eq!("1 2 3\n", fs::read_to_string(&path).unwrap());
+        assert_eq!(
+            vec![(
+                String::from("http://localhost:8080/2023/day/6/input"),
+                String::from("session=abc")
+            )],
+            *client.requests.borrow()
+        );
+
+        assert_eq!(
+            vec![Fetched::Cached],
+            fetch_input(&client, &config(), 2023, 6, &[path], false).unwrap()
+        );
+        assert_eq!(1, client.requests.borrow().len());
+    }
+
+    #[test]
+    fn test_download_once_for_every_crate() {
+        let (cached, missing) = (temp_input("several-cached"), temp_input("several-missing"));
+        fs::write(&cached, "4 5 6\n").unwrap();
+        let locked = temp_input("several-locked");
+        fs::write(&locked, b"\0GITCRYPT\0\x01\x02").unwrap();
+
+        let client = MockClient::new(200, "1 2 3\n");
+        let paths = [cached.clone(), missing.clone(), locked.clone()];
+
+        assert_eq!(
+            vec![Fetched::Cached, Fetched::Downloaded, Fetched::Downloaded],
+            fetch_input(&client, &config(), 2023, 1, &paths, false).unwrap()
+        );
+        assert_eq!(1, client.requests.borrow().len());
+        assert_eq!("4 5 6\n", fs::read_to_string(&cached).unwrap());
+        assert_eq!("1 2 3\n", fs::read_to_string(&missing).unwrap());
+        assert_eq!("1 2 3\n", fs::read_to_string(&locked).unwrap());
+    }
+
+    #[test]
+    fn test_keep_locked_tracked_input() {
+        let dir = env::temp_dir().join("aoc-fetch-tracked");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(DEFAULT_INPUT);
+        fs::write(&path, b"\0GITCRYPT\0\x01\x02").unwrap();
+
+        let git = |args: &[&str]| {
+            let status = Command::new("git").arg("-C").arg(&dir).args(args).status();
+            assert!(status.unwrap().success());
+        };
+        git(&["init", "--quiet"]);
+        git(&["add", DEFAULT_INPUT]);
+
+        let client = MockClient::new(200, "1 2 3\n");
+        let err =
+            fetch_input(&client, &config(), 2023, 6, slice::from_ref(&path), false).unwrap_err();
+        assert!(err
+            .to_string()
+            .ends_with("is encrypted by git-crypt and tracked by git, replacing it while the repository is locked would commit the input in plain text; run `git-crypt unlock` first, or pass --force to replace it anyway"));
+        assert!(client.requests.borrow().is_empty());
+
+        assert_eq!(
+            vec![Fetched::Downloaded],
+            fetch_input(&client, &config(), 2023, 6, slice::from_ref(&path), true).unwrap()
+        );
+        assert_eq!("1 2 3\n", fs::read_to_string(&path).unwrap());
+    }
+
+    #[test]
+    fn test_failures() {
+        let path = temp_input("failures");
+        let paths = [path.clone()];
+
+        let err = fetch_input(
+            &MockClient::new(404, ""),
+            &config(),
+            2023,
+            25,
+            &paths,
+            false,
+        )
+        .unwrap_err();
+        assert_eq!(
+            "the input of 2023 day 25 is not available yet",
+            err.to_string()
+        );
+
+        let err =
+            fetch_input(&MockClient::new(400, ""), &config(), 2023, 6, &paths, false).unwrap_err();
+        assert_eq!(
+            "the session token was rejected (HTTP 400), it may have expired",
+            err.to_string()
+        );
+
+        let err = fetch_input(
+            &MockClient::new(200, ""),
+            &Config::default(),
+            2023,
+            6,
+            &paths,
+            false,
+        )
+        .unwrap_err();
+        assert!(err.to_string().starts_with("no session token configured"));
+
+        assert!(!path.exists());
+    }
+}
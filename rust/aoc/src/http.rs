@@ -0,0 +1,105 @@
+use anyhow::Result;
+use std::time::Duration;
+
+/// User agent sent to adventofcode.com, as requested by its automation guidelines
+const USER_AGENT: &str = "github.com/carminexx/advent-of-code aoc runner";
+
+/// Response to an HTTP request, successful or not
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Minimal HTTP layer of the runner, so that it can be replaced by a mock
+/// (or pointed at a local server) when testing offline.
+pub trait HttpClient {
+    /// Sends a GET request with the given headers; only transport failures are
+    /// errors, while any status code is returned in the response.
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Response>;
+}
+
+/// Client backed by `ureq`, used to reach the real website
+pub struct UreqClient {
+    agent: ureq::Agent,
+}
+
+impl UreqClient {
+    pub fn new() -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        UreqClient { agent }
+    }
+}
+
+impl HttpClient for UreqClient {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Response> {
+        let mut request = self.agent.get(url);
+        for (name, value) in headers {
+            request = request.set(name, value);
+        }
+
+        let response = match request.call() {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(Response {
+            status: response.status(),
+            body: response.into_string()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    #[test]
+    fn test_ureq_client_with_local_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let request: Vec<String> = BufReader::new(&stream)
+                .lines()
+                .map(Result::unwrap)
+                .take_while(|line| !line.is_empty())
+                .collect();
+
+            stream
+                .write_all(b"HTTP/1.1 404 Not Found\r\nContent-Length: 9\r\nConnection: close\r\n\r\nnot found")
+                .unwrap();
+
+            request
+        });
+
+        let response = UreqClient::new()
+            .get(
+                &format!("http://{address}/2023/day/4/input"),
+                &[("Cookie", "session=abc")],
+            )
+            .unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(
+            Response {
+                status: 404,
+                body: String::from("not found")
+            },
+            response
+        );
+        assert_eq!("GET /2023/day/4/input HTTP/1.1", request[0]);
+        assert!(request.iter().any(|line| line == "Cookie: session=abc"));
+    }
+}
@@ -0,0 +1,189 @@
+mod bench;
+mod config;
+mod fetch;
+mod http;
+mod output;
+mod readme;
+mod registry;
+mod run;
+mod scaffold;
+mod verify;
+
+use anyhow::anyhow;
+use aoc_common::InputSource;
+use clap::{Parser, Subcommand};
+use output::Format;
+use std::{path::PathBuf, process::ExitCode};
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code solutions of this repository")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solutions of a single puzzle, or of all of them
+    Run {
+        #[arg(required_unless_present = "all")]
+        year: Option<u16>,
+        #[arg(required_unless_present = "all")]
+        day: Option<u8>,
+
+        /// Only run the given part of the puzzle
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Input file to solve, or "-" to read it from stdin [default: input.txt of the day]
+        #[arg(long)]
+        input: Option<String>,
+
+        /// Run every solution in parallel with its own input, then print a summary
+        #[arg(long, conflicts_with_all = ["year", "day", "part", "input"])]
+        all: bool,
+
+        /// With --all, only run the solutions of the given year
+        #[arg(
+            long = "year",
+            id = "all_year",
+            value_name = "YEAR",
+            requires = "all",
+            conflicts_with_all = ["year", "day", "part", "input"]
+        )]
+        all_year: Option<u16>,
+
+        /// Format of the answers
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+    },
+    /// Create the crate of a new day and register it with the runner
+    New {
+        #[arg(value_parser = clap::value_parser!(u16).range(2015..))]
+        year: u16,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Title of the puzzle, listed in the README
+        title: String,
+    },
+    /// Download the personal input of a puzzle into the folder of the day
+    Fetch {
+        year: u16,
+        day: u8,
+
+        /// Download the input even when it's already there
+        #[arg(long)]
+        force: bool,
+    },
+    /// Time the parsing and each part of every day (of a year, or a single day)
+    Bench {
+        year: Option<u16>,
+        day: Option<u8>,
+
+        /// Number of runs of each day
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+
+        /// Save the results as a baseline into the given file
+        #[arg(long)]
+        save_baseline: Option<PathBuf>,
+
+        /// Compare the results with the baseline saved in the given file
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+    },
+    /// Rewrite the progress tables of the README from the solutions and the answers file
+    Readme {
+        /// Only check that the tables are up to date, failing otherwise
+        #[arg(long)]
+        check: bool,
+    },
+    /// Run every day with its own input and check the answers against the answers file
+    Verify {
+        /// Only verify the days of the given year
+        #[arg(long)]
+        year: Option<u16>,
+    },
+}
+
+fn main() -> ExitCode {
+    let result = match Cli::parse().command {
+        Command::Run {
+            all: true,
+            all_year,
+            format,
+            ..
+        } => run::run_all(all_year, format),
+        Command::Run {
+            year: Some(year),
+            day: Some(day),
+            part,
+            input,
+            format,
+            ..
+        } => run::run_day(
+            year,
+            day,
+            part,
+            input.as_deref().map(InputSource::from),
+            format,
+        ),
+        Command::Run { .. } => Err(anyhow!("the year and day to run are missing")),
+        Command::New { year, day, title } => scaffold::new_day(year, day, &title),
+        Command::Fetch { year, day, force } => fetch::fetch(year, day, force),
+        Command::Bench {
+            year,
+            day,
+            iterations,
+            save_baseline,
+            baseline,
+        } => bench::bench(
+            year,
+            day,
+            iterations,
+            save_baseline.as_deref(),
+            baseline.as_deref(),
+        ),
+        Command::Readme { check } => readme::readme(check),
+        Command::Verify { year } => verify::verify(year),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use clap::error::ErrorKind;
+
+    #[test]
+    fn test_year_filter_requires_all() {
+        let parse = |args: &str| Cli::try_parse_from(args.split_whitespace());
+
+        assert!(matches!(
+            parse("aoc run --all --year 2023").unwrap().command,
+            Command::Run {
+                all: true,
+                all_year: Some(2023),
+                ..
+            }
+        ));
+        assert_eq!(
+            ErrorKind::ArgumentConflict,
+            parse("aoc run 2022 2 --year 2023 --input -")
+                .err()
+                .unwrap()
+                .kind()
+        );
+        assert_eq!(
+            ErrorKind::MissingRequiredArgument,
+            parse("aoc run --year 2023").err().unwrap().kind()
+        );
+    }
+}
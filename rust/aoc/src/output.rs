@@ -0,0 +1,183 @@
+use crate::registry::{Entry, Run};
+use clap::ValueEnum;
+use serde::Serialize;
+
+/// How the runner prints the answers
+#[derive(Debug, Clone, Copy, Default, PartialEq, ValueEnum)]
+pub enum Format {
+    /// One line per answer, for humans
+    #[default]
+    Text,
+    /// A JSON array with an object per answer, for scripts
+    Json,
+}
+
+/// Whether a part, or a whole day, was solved
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    Failed,
+    /// The input is still encrypted, which is not a failure of the solution
+    Locked,
+}
+
+/// Answer of a part, as printed in JSON
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Report {
+    pub year: u16,
+    pub day: u8,
+    /// Missing when the whole day failed or couldn't run
+    pub part: Option<u8>,
+    pub status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer: Option<String>,
+    /// Why the part, or the whole day, failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub variant: Option<&'static str>,
+    /// Time taken to parse the input, shared by the parts of the same run
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_time_ns: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solve_time_ns: Option<u64>,
+}
+
+impl Report {
+    pub fn from_run(entry: &Entry, run: &Run) -> Vec<Report> {
+        run.answers
+            .iter()
+            .map(|answer| Report {
+                year: entry.year,
+                day: entry.day,
+                part: Some(answer.part),
+                status: match answer.answer {
+                    Ok(_) => Status::Ok,
+                    Err(_) => Status::Failed,
+                },
+                answer: answer.answer.as_ref().ok().cloned(),
+                error: answer.answer.as_ref().err().map(|err| format!("{err:#}")),
+                variant: entry.variant,
+                parse_time_ns: Some(run.parse_time.as_nanos() as u64),
+                solve_time_ns: Some(answer.elapsed.as_nanos() as u64),
+            })
+            .collect()
+    }
+
+    /// Report of a day that failed, or couldn't run, before solving any part
+    pub fn unsolved(entry: &Entry, status: Status, error: Option<String>) -> Report {
+        Report {
+            year: entry.year,
+            day: entry.day,
+            part: None,
+            status,
+            answer: None,
+            error,
+            variant: entry.variant,
+            parse_time_ns: None,
+            solve_time_ns: None,
+        }
+    }
+}
+
+/// Aligns the cells of a table in columns, separated by two spaces
+pub fn format_table<const N: usize>(header: [&str; N], rows: &[[String; N]]) -> String {
+    let mut widths = header.map(|cell| cell.chars().count());
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |row: [&str; N]| {
+        let line = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+
+        format!("{}\n", line.trim_end())
+    };
+
+    let mut table = format_row(header);
+    for row in rows {
+        table.push_str(&format_row(row.each_ref().map(String::as_str)));
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::registry::{self, Answer};
+    use anyhow::anyhow;
+    use std::time::Duration;
+
+    #[test]
+    fn test_format_table() {
+        let rows = [
+            [String::from("2023"), String::from("24"), String::from("")],
+            [String::from("2022"), String::from("1"), String::from("ok")],
+        ];
+
+        assert_eq!(
+            "Year  Day  Status\n2023  24\n2022  1    ok\n",
+            format_table(["Year", "Day", "Status"], &rows)
+        );
+    }
+
+    #[test]
+    fn test_json_report() {
+        let entry = registry::find(2023, 24)[0];
+        let run = Run {
+            parse_time: Duration::from_micros(3),
+            answers: vec![Answer {
+                part: 1,
+                answer: Ok(String::from("27328")),
+                elapsed: Duration::from_millis(2),
+            }],
+        };
+
+        assert_eq!(
+            r#"[{"year":2023,"day":24,"part":1,"status":"ok","answer":"27328","variant":"approach A","parse_time_ns":3000,"solve_time_ns":2000000}]"#,
+            serde_json::to_string(&Report::from_run(entry, &run)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_json_report_with_error() {
+        let entry = registry::find(2023, 8)[0];
+        let run = Run {
+            parse_time: Duration::from_micros(3),
+            answers: vec![
+                Answer {
+                    part: 1,
+                    answer: Err(anyhow!("expected a single start node, found 0")),
+                    elapsed: Duration::from_micros(1),
+                },
+                Answer {
+                    part: 2,
+                    answer: Ok(String::from("6")),
+                    elapsed: Duration::from_micros(2),
+                },
+            ],
+        };
+
+        assert_eq!(
+            r#"[{"year":2023,"day":8,"part":1,"status":"failed","error":"expected a single start node, found 0","variant":null,"parse_time_ns":3000,"solve_time_ns":1000},{"year":2023,"day":8,"part":2,"status":"ok","answer":"6","variant":null,"parse_time_ns":3000,"solve_time_ns":2000}]"#,
+            serde_json::to_string(&Report::from_run(entry, &run)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_json_report_unsolved() {
+        let entry = registry::find(2022, 1)[0];
+
+        assert_eq!(
+            r#"{"year":2022,"day":1,"part":null,"status":"locked","variant":null}"#,
+            serde_json::to_string(&Report::unsolved(entry, Status::Locked, None)).unwrap()
+        );
+    }
+}
@@ -0,0 +1,451 @@
+use crate::registry::{self, ENTRIES};
+use anyhow::{bail, Context, Result};
+use aoc_common::AnswerBook;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::Path,
+};
+use toml::{Table, Value};
+
+/// Address of the sources linked from the README
+const REPOSITORY_URL: &str = "https://github.com/carminexx/Advent-Of-Code/blob/main/rust";
+
+const START_MARKER: &str = "<!-- progress:start -->\n";
+const END_MARKER: &str = "<!-- progress:end -->\n";
+
+/// Titles of the puzzles listed in the README, including the ones without a
+/// solution yet, relative to the workspace root
+const TITLES_FILE: &str = "titles.toml";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Status {
+    /// Solved, and the answer is recorded in the answers file
+    Verified,
+    Unverified,
+    Todo,
+}
+
+impl Status {
+    fn mark(self) -> &'static str {
+        match self {
+            Status::Verified => "✓",
+            Status::Unverified => "unverified",
+            Status::Todo => "todo",
+        }
+    }
+}
+
+/// A row of the progress tables
+struct Row {
+    year: u16,
+    day: u8,
+    title: String,
+    /// Folders of the crates solving the puzzle
+    dirs: Vec<String>,
+    parts: [Status; 2],
+}
+
+/// Rewrites the progress tables of the README from the registry and the
+/// answers file; with `check`, fails instead when they're not up to date.
+pub fn readme(check: bool) -> Result<()> {
+    update(check, None)
+}
+
+/// Records the title of a day just created by `new`, then rewrites the
+/// progress tables with its crate, which the registry compiled into the runner
+/// doesn't list yet.
+pub fn add_day(year: u16, day: u8, dir: &str, title: &str) -> Result<()> {
+    let path = registry::workspace_dir().join(TITLES_FILE);
+    let mut titles = load_titles(&path)?;
+    titles.insert((year, day), title.to_owned());
+    fs::write(&path, format_titles(&titles))
+        .with_context(|| format!("failed to write {}", path.display()))?;
+
+    update(false, Some((year, day, dir)))
+}
+
+fn update(check: bool, new_day: Option<(u16, u8, &str)>) -> Result<()> {
+    let path = registry::workspace_dir().join("../README.md");
+    let readme =
+        fs::read_to_string(&path).with_context(|| format!("failed to read {}", path.display()))?;
+
+    // Only the answers of the repository count, regardless of AOC_ANSWERS
+    let answers = AnswerBook::load_from(&registry::workspace_dir().join("answers.toml"))?;
+    let titles = load_titles(&registry::workspace_dir().join(TITLES_FILE))?;
+    let mut rows = rows(&answers, titles)?;
+    if let Some((year, day, dir)) = new_day {
+        add_row(&mut rows, year, day, dir);
+    }
+    let updated = replace_progress(&readme, &progress(&rows))?;
+
+    if updated == readme {
+        println!("README.md is up to date");
+    } else if check {
+        bail!("README.md is out of date, run `cargo run -p aoc -- readme` to update it");
+    } else {
+        fs::write(&path, updated).with_context(|| format!("failed to write {}", path.display()))?;
+        println!("README.md updated");
+    }
+
+    Ok(())
+}
+
+/// Titles of the puzzles by year and day, from a TOML table keyed by year
+/// then day, e.g.:
+///
+/// ```toml
+/// [2023]
+/// 1 = "Trebuchet?!"
+/// ```
+fn parse_titles(text: &str) -> Result<BTreeMap<(u16, u8), String>> {
+    let mut titles = BTreeMap::new();
+
+    for (year, days) in text.parse::<Table>()? {
+        let year: u16 = year
+            .parse()
+            .with_context(|| format!("invalid year \"{year}\""))?;
+        let days = days
+            .as_table()
+            .with_context(|| format!("expected a table of days for {year}"))?;
+
+        for (day, title) in days {
+            let day: u8 = day
+                .parse()
+                .with_context(|| format!("invalid day \"{day}\" of {year}"))?;
+            let title = title
+                .as_str()
+                .with_context(|| format!("expected the title of {year} day {day} as a string"))?;
+            titles.insert((year, day), title.to_owned());
+        }
+    }
+
+    Ok(titles)
+}
+
+fn load_titles(path: &Path) -> Result<BTreeMap<(u16, u8), String>> {
+    let text =
+        fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+
+    parse_titles(&text).with_context(|| format!("failed to parse {}", path.display()))
+}
+
+/// The titles file, one table per year, in order
+fn format_titles(titles: &BTreeMap<(u16, u8), String>) -> String {
+    let mut text =
+        String::from("# Titles of the puzzles listed in the README, recorded by `aoc new`\n");
+    let mut last_year = None;
+
+    for (&(year, day), title) in titles {
+        if last_year != Some(year) {
+            text.push_str(&format!("\n[{year}]\n"));
+            last_year = Some(year);
+        }
+        text.push_str(&format!("{day} = {}\n", Value::from(title.as_str())));
+    }
+
+    text
+}
+
+/// Rows of the puzzles that have a title or a solution; every one of them
+/// must have a title, rather than a placeholder in the README
+fn rows(answers: &AnswerBook, mut titles: BTreeMap<(u16, u8), String>) -> Result<Vec<Row>> {
+    let days: BTreeSet<(u16, u8)> = titles
+        .keys()
+        .copied()
+        .chain(ENTRIES.iter().map(|entry| (entry.year, entry.day)))
+        .collect();
+
+    days.into_iter()
+        .map(|(year, day)| {
+            let title = titles.remove(&(year, day)).with_context(|| {
+                format!("no title for {year} day {day}, add it to rust/{TITLES_FILE}")
+            })?;
+            let entries = registry::find(year, day);
+
+            let mut dirs: Vec<String> = entries.iter().map(|entry| entry.dir.to_owned()).collect();
+            dirs.dedup();
+
+            let parts = [1, 2].map(|part| {
+                if !entries.iter().any(|entry| (entry.parts)().contains(&part)) {
+                    Status::Todo
+                } else if answers.get(year, day, part).is_some() {
+                    Status::Verified
+                } else {
+                    Status::Unverified
+                }
+            });
+
+            Ok(Row {
+                year,
+                day,
+                title,
+                dirs,
+                parts,
+            })
+        })
+        .collect()
+}
+
+/// Adds the folder of a new crate to the row of its day, whose title is
+/// already recorded
+fn add_row(rows: &mut [Row], year: u16, day: u8, dir: &str) {
+    if let Some(row) = rows
+        .iter_mut()
+        .find(|row| (row.year, row.day) == (year, day))
+    {
+        if !row.dirs.iter().any(|existing| existing == dir) {
+            row.dirs.push(dir.to_owned());
+        }
+    }
+}
+
+/// Markdown of the tables, latest year first
+fn progress(rows: &[Row]) -> String {
+    let years: BTreeSet<u16> = rows.iter().map(|row| row.year).collect();
+    // The days line up across the tables, whichever days each year lists
+    let day_width = rows
+        .iter()
+        .map(|row| format!("Day {}", row.day).len())
+        .max()
+        .unwrap_or(0);
+    let mut markdown = String::new();
+
+    for year in years.into_iter().rev() {
+        let rows: Vec<&Row> = rows.iter().filter(|row| row.year == year).collect();
+
+        let mut cells = vec![[
+            String::new(),
+            String::from("Language"),
+            String::from("Problem"),
+            String::from("Solution"),
+            String::from("Part 1"),
+            String::from("Part 2"),
+        ]];
+        // Days in between the listed ones are skipped with a "..." row
+        let mut gaps = Vec::new();
+
+        for (idx, row) in rows.iter().enumerate() {
+            if idx > 0 && row.day > rows[idx - 1].day + 1 {
+                gaps.push(cells.len());
+            }
+
+            let url = format!("https://adventofcode.com/{year}/day/{}", row.day);
+            let solution = row
+                .dirs
+                .iter()
+                .map(|dir| {
+                    let name = dir.rsplit('/').next().unwrap_or(dir);
+                    format!("[{name}]({REPOSITORY_URL}/{dir}/src/lib.rs)")
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            cells.push([
+                format!("Day {}", row.day),
+                String::from("Rust"),
+                format!("[{}]({url})", row.title),
+                solution,
+                row.parts[0].mark().to_owned(),
+                row.parts[1].mark().to_owned(),
+            ]);
+        }
+
+        let mut widths = [day_width, 0, 0, 0, 0, 0];
+        for row in &cells {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        markdown.push_str(&format!("## {year}\n\n"));
+
+        for (idx, row) in cells.iter().enumerate() {
+            if gaps.contains(&idx) {
+                markdown.push_str(&format!("| {:<1$} |\n", "...", widths[0]));
+            }
+
+            markdown.push_str(&table_line(row.each_ref().map(String::as_str), widths));
+
+            if idx == 0 {
+                markdown.push_str(&table_line(widths.map(|_| ""), widths).replace(' ', "-"));
+            }
+        }
+
+        markdown.push('\n');
+    }
+
+    markdown.push_str(
+        "Parts marked with ✓ have their answer recorded in `rust/answers.toml`, while `unverified` ones are solved but their answer isn't recorded yet.\n",
+    );
+
+    markdown
+}
+
+fn table_line(cells: [&str; 6], widths: [usize; 6]) -> String {
+    let cells: Vec<String> = cells
+        .iter()
+        .zip(widths)
+        .map(|(cell, width)| format!(" {cell}{} ", " ".repeat(width - cell.chars().count())))
+        .collect();
+
+    format!("|{}|\n", cells.join("|"))
+}
+
+/// Replaces the text in between the progress markers of the README
+fn replace_progress(readme: &str, progress: &str) -> Result<String> {
+    let start = readme
+        .find(START_MARKER)
+        .context("progress start marker not found in the README")?
+        + START_MARKER.len();
+    let end = readme[start..]
+        .find(END_MARKER)
+        .context("progress end marker not found in the README")?
+        + start;
+
+    Ok(format!("{}{progress}{}", &readme[..start], &readme[end..]))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_progress() {
+        let rows = [
+            Row {
+                year: 2023,
+                day: 1,
+                title: String::from("Trebuchet?!"),
+                dirs: vec![String::from("2023/day-1"), String::from("2023/day-1-bis")],
+                parts: [Status::Verified, Status::Unverified],
+            },
+            Row {
+                year: 2023,
+                day: 24,
+                title: String::from("Never Tell Me The Odds"),
+                dirs: Vec::new(),
+                parts: [Status::Todo, Status::Todo],
+            },
+            Row {
+                year: 2022,
+                day: 9,
+                title: String::from("Rope Bridge"),
+                dirs: vec![String::from("2022/day-9")],
+                parts: [Status::Todo, Status::Todo],
+            },
+        ];
+
+        let markdown = progress(&rows);
+        let lines: Vec<&str> = markdown.lines().collect();
+
+        assert_eq!("## 2023", lines[0]);
+        assert_eq!(
+            "|        | Language | Problem",
+            &lines[2][..lines[2].find(" Problem").unwrap() + 8]
+        );
+        assert!(lines[3].starts_with("|--------|----------|---"));
+        assert!(lines[4].starts_with(
+            "| Day 1  | Rust     | [Trebuchet?!](https://adventofcode.com/2023/day/1) "
+        ));
+        assert!(lines[4].contains(" | [day-1]("));
+        assert_eq!("| ...    |", lines[5]);
+        assert!(lines[4].contains("[day-1-bis]("));
+        assert!(lines[4].ends_with("| ✓      | unverified |"));
+        assert!(lines[6].starts_with(
+            "| Day 24 | Rust     | [Never Tell Me The Odds](https://adventofcode.com/2023/day/24) "
+        ));
+        assert!(lines[6].ends_with("| todo   | todo       |"));
+        assert_eq!(lines[2].chars().count(), lines[4].chars().count());
+
+        // The days of the other years line up with the ones of 2023
+        assert_eq!("## 2022", lines[8]);
+        assert!(lines[12].starts_with("| Day 9  | Rust     | [Rope Bridge]("));
+    }
+
+    fn titles() -> BTreeMap<(u16, u8), String> {
+        load_titles(&registry::workspace_dir().join(TITLES_FILE)).unwrap()
+    }
+
+    #[test]
+    fn test_add_row() {
+        let mut titles = titles();
+        titles.insert((2023, 9), String::from("Mirage Maintenance"));
+        let mut rows = rows(&AnswerBook::default(), titles).unwrap();
+        let len = rows.len();
+
+        add_row(&mut rows, 2023, 5, "2023/day-5");
+        add_row(&mut rows, 2023, 9, "2023/day-9");
+        add_row(&mut rows, 2023, 9, "2023/day-9");
+
+        assert_eq!(len, rows.len());
+        let day5 = rows
+            .iter()
+            .find(|row| (row.year, row.day) == (2023, 5))
+            .unwrap();
+        assert_eq!("If You Give A Seed A Fertilizer", day5.title);
+        assert_eq!(vec![String::from("2023/day-5")], day5.dirs);
+        assert!(rows.is_sorted_by_key(|row| (row.year, row.day)));
+        let day9 = rows.iter().find(|row| row.day == 9).unwrap();
+        assert_eq!("Mirage Maintenance", day9.title);
+        assert_eq!(vec![String::from("2023/day-9")], day9.dirs);
+        assert_eq!([Status::Todo; 2], day9.parts);
+    }
+
+    #[test]
+    fn test_missing_title() {
+        let mut titles = titles();
+        titles.remove(&(2023, 24));
+
+        assert_eq!(
+            "no title for 2023 day 24, add it to rust/titles.toml",
+            rows(&AnswerBook::default(), titles)
+                .err()
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_titles() {
+        let titles = BTreeMap::from([
+            ((2023, 24), String::from("Never Tell Me The Odds")),
+            ((2022, 1), String::from("Calorie Counting")),
+            ((2023, 1), String::from("Trebuchet?!")),
+            ((2023, 2), String::from("The \"Cube\" Conundrum")),
+        ]);
+
+        let text = format_titles(&titles);
+        assert!(text.ends_with(
+            r#"
+[2022]
+1 = "Calorie Counting"
+
+[2023]
+1 = "Trebuchet?!"
+2 = 'The "Cube" Conundrum'
+24 = "Never Tell Me The Odds"
+"#
+        ));
+        assert_eq!(titles, parse_titles(&text).unwrap());
+
+        assert!(parse_titles("[2023]\nfirst = \"Trebuchet?!\"\n").is_err());
+        assert!(parse_titles("[2023]\n1 = 1\n").is_err());
+    }
+
+    #[test]
+    fn test_replace_progress() {
+        let readme = "# Title\n<!-- progress:start -->\nold\n<!-- progress:end -->\n# Next\n";
+
+        assert_eq!(
+            "# Title\n<!-- progress:start -->\nnew\n<!-- progress:end -->\n# Next\n",
+            replace_progress(readme, "new\n").unwrap()
+        );
+        assert!(replace_progress("# Title\n", "new\n").is_err());
+    }
+
+    #[test]
+    fn test_readme_is_up_to_date() {
+        readme(true).unwrap();
+    }
+}
@@ -0,0 +1,255 @@
+use anyhow::{Context, Result};
+use aoc_common::{InputSource, Solution, DEFAULT_INPUT};
+use std::{
+    any::TypeId,
+    convert::Infallible,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+/// Answers of a day, together with the time taken to find them
+pub struct Run {
+    pub parse_time: Duration,
+    pub answers: Vec<Answer>,
+}
+
+pub struct Answer {
+    pub part: u8,
+    /// The error of a part that fails, kept apart from the answers of the other parts
+    pub answer: Result<String>,
+    pub elapsed: Duration,
+}
+
+/// A day crate of the workspace, together with the puzzle it solves.
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    /// Name of the approach, for days that solve the same puzzle in more than one way
+    pub variant: Option<&'static str>,
+    /// Folder of the day crate, relative to the workspace root
+    pub dir: &'static str,
+    /// Parses the input once, then returns the answers of the given parts that the day solves
+    pub solve: fn(input: &str, parts: &[u8]) -> Result<Run>,
+    /// Parts that the day solves
+    pub parts: fn() -> Vec<u8>,
+}
+
+impl Entry {
+    /// The "input.txt" file within the folder of the day crate
+    pub fn default_input(&self) -> InputSource {
+        workspace_dir().join(self.dir).join(DEFAULT_INPUT).into()
+    }
+}
+
+/// Root folder of the Cargo workspace, where the folders of the years are
+pub fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+pub const ENTRIES: &[Entry] = &[
+    Entry {
+        year: 2022,
+        day: 1,
+        variant: None,
+        dir: "2022/day-1",
+        solve: solve::<aoc_2022_day_1::Day1>,
+        parts: parts::<aoc_2022_day_1::Day1>,
+    },
+    Entry {
+        year: 2022,
+        day: 2,
+        variant: None,
+        dir: "2022/day-2",
+        solve: solve::<aoc_2022_day_2::Day2>,
+        parts: parts::<aoc_2022_day_2::Day2>,
+    },
+    Entry {
+        year: 2022,
+        day: 2,
+        variant: None,
+        dir: "2022/day-2-bis",
+        solve: solve::<aoc_2022_day_2_bis::Day2Bis>,
+        parts: parts::<aoc_2022_day_2_bis::Day2Bis>,
+    },
+    Entry {
+        year: 2022,
+        day: 3,
+        variant: None,
+        dir: "2022/day-3",
+        solve: solve::<aoc_2022_day_3::Day3>,
+        parts: parts::<aoc_2022_day_3::Day3>,
+    },
+    Entry {
+        year: 2023,
+        day: 1,
+        variant: None,
+        dir: "2023/day-1",
+        solve: solve::<aoc_2023_day_1::Day1>,
+        parts: parts::<aoc_2023_day_1::Day1>,
+    },
+    Entry {
+        year: 2023,
+        day: 1,
+        variant: None,
+        dir: "2023/day-1-bis",
+        solve: solve::<aoc_2023_day_1_bis::Day1Bis>,
+        parts: parts::<aoc_2023_day_1_bis::Day1Bis>,
+    },
+    Entry {
+        year: 2023,
+        day: 2,
+        variant: None,
+        dir: "2023/day-2",
+        solve: solve::<aoc_2023_day_2::Day2>,
+        parts: parts::<aoc_2023_day_2::Day2>,
+    },
+    Entry {
+        year: 2023,
+        day: 3,
+        variant: None,
+        dir: "2023/day-3",
+        solve: solve::<aoc_2023_day_3::Day3>,
+        parts: parts::<aoc_2023_day_3::Day3>,
+    },
+    Entry {
+        year: 2023,
+        day: 4,
+        variant: None,
+        dir: "2023/day-4",
+        solve: solve::<aoc_2023_day_4::Day4>,
+        parts: parts::<aoc_2023_day_4::Day4>,
+    },
+    Entry {
+        year: 2023,
+        day: 4,
+        variant: Some("simulation"),
+        dir: "2023/day-4",
+        solve: solve::<aoc_2023_day_4::Day4Simulation>,
+        parts: parts::<aoc_2023_day_4::Day4Simulation>,
+    },
+    Entry {
+        year: 2023,
+        day: 6,
+        variant: None,
+        dir: "2023/day-6",
+        solve: solve::<aoc_2023_day_6::Day6>,
+        parts: parts::<aoc_2023_day_6::Day6>,
+    },
+    Entry {
+        year: 2023,
+        day: 8,
+        variant: None,
+        dir: "2023/day-8",
+        solve: solve::<aoc_2023_day_8::Day8>,
+        parts: parts::<aoc_2023_day_8::Day8>,
+    },
+    Entry {
+        year: 2023,
+        day: 24,
+        variant: Some("approach A"),
+        dir: "2023/day-24",
+        solve: solve::<aoc_2023_day_24::Day24>,
+        parts: parts::<aoc_2023_day_24::Day24>,
+    },
+    Entry {
+        year: 2023,
+        day: 24,
+        variant: Some("approach B"),
+        dir: "2023/day-24",
+        solve: solve::<aoc_2023_day_24::Day24LineSegments>,
+        parts: parts::<aoc_2023_day_24::Day24LineSegments>,
+    },
+    Entry {
+        year: 2023,
+        day: 24,
+        variant: Some("approach C"),
+        dir: "2023/day-24",
+        solve: solve::<aoc_2023_day_24::Day24Grid>,
+        parts: parts::<aoc_2023_day_24::Day24Grid>,
+    },
+];
+
+fn solve<S: Solution>(input: &str, parts: &[u8]) -> Result<Run> {
+    let start = Instant::now();
+    let input = S::parse(input).context("failed to parse the input")?;
+    let parse_time = start.elapsed();
+
+    let mut answers = Vec::new();
+
+    for &part in parts {
+        let start = Instant::now();
+        let answer = match part {
+            1 => S::part1(&input).map(|answer| answer.map(|answer| answer.to_string())),
+            2 => S::part2(&input).map(|answer| answer.map(|answer| answer.to_string())),
+            _ => Ok(None),
+        };
+        let elapsed = start.elapsed();
+
+        if let Some(answer) = answer.transpose() {
+            answers.push(Answer {
+                part,
+                answer,
+                elapsed,
+            });
+        }
+    }
+
+    Ok(Run {
+        parse_time,
+        answers,
+    })
+}
+
+/// Parts whose answer type is not `Infallible`
+fn parts<S: Solution>() -> Vec<u8>
+where
+    S::Answer1: 'static,
+    S::Answer2: 'static,
+{
+    let unsolved = TypeId::of::<Infallible>();
+
+    [
+        (1, TypeId::of::<S::Answer1>()),
+        (2, TypeId::of::<S::Answer2>()),
+    ]
+    .into_iter()
+    .filter(|&(_, answer)| answer != unsolved)
+    .map(|(part, _)| part)
+    .collect()
+}
+
+pub fn find(year: u16, day: u8) -> Vec<&'static Entry> {
+    ENTRIES
+        .iter()
+        .filter(|entry| entry.year == year && entry.day == day)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_failed_part_keeps_other_answers() {
+        let input = include_str!("../../2023/day-8/demo-input-part-2.txt");
+        let run = (find(2023, 8)[0].solve)(input, &[1, 2]).unwrap();
+
+        let answers: Vec<(u8, String)> = run
+            .answers
+            .into_iter()
+            .map(|answer| {
+                (
+                    answer.part,
+                    answer.answer.unwrap_or_else(|err| err.to_string()),
+                )
+            })
+            .collect();
+        assert_eq!(
+            vec![
+                (1, String::from("expected a single start node, found 0")),
+                (2, String::from("6"))
+            ],
+            answers
+        );
+    }
+}
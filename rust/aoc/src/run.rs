@@ -0,0 +1,326 @@
+use crate::{
+    output::{format_table, Format, Report, Status},
+    registry::{self, Entry, Run, ENTRIES},
+};
+use anyhow::{bail, Context, Error, Result};
+use aoc_common::{InputSource, LockedInputError};
+use rayon::prelude::*;
+use std::{
+    cmp::Reverse,
+    time::{Duration, Instant},
+};
+
+/// Runs the solutions of a single puzzle, with the given input or with the one
+/// of each solution.
+pub fn run_day(
+    year: u16,
+    day: u8,
+    part: Option<u8>,
+    source: Option<InputSource>,
+    format: Format,
+) -> Result<()> {
+    let entries = registry::find(year, day);
+
+    if entries.is_empty() {
+        bail!("no solution registered for {year} day {day}");
+    }
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let mut reports = Vec::new();
+
+    for entry in entries {
+        let source = source.clone().unwrap_or_else(|| entry.default_input());
+        let input = source
+            .read_to_string()
+            .with_context(|| format!("failed to read the input from {source}"))?;
+
+        let run = (entry.solve)(&input, &parts).with_context(|| describe(entry))?;
+
+        reports.append(&mut Report::from_run(entry, &run));
+    }
+
+    if reports.is_empty() {
+        bail!("no solution registered for the requested parts of {year} day {day}");
+    }
+
+    match format {
+        Format::Text => {
+            for report in &reports {
+                // Every report of a run has a part
+                let part = report.part.unwrap_or_default();
+                let part = match report.variant {
+                    Some(variant) => format!("Part {part} ({variant})"),
+                    None => format!("Part {part}"),
+                };
+                match (&report.answer, &report.error) {
+                    (Some(answer), _) => println!("{part}: {answer}"),
+                    (None, error) => {
+                        println!("{part}: FAILED, {}", error.as_deref().unwrap_or("-"))
+                    }
+                }
+            }
+        }
+        Format::Json => println!("{}", serde_json::to_string_pretty(&reports)?),
+    }
+
+    let failures = reports
+        .iter()
+        .filter(|report| report.status == Status::Failed)
+        .count();
+    if failures > 0 {
+        bail!("{failures} of {} parts failed", reports.len());
+    }
+
+    Ok(())
+}
+
+enum Outcome {
+    Solved(Run),
+    /// The input is still encrypted, which is not a failure of the solution
+    Locked,
+    Failed(Error),
+}
+
+struct Summary {
+    entry: &'static Entry,
+    outcome: Outcome,
+}
+
+impl Summary {
+    /// Time taken to parse the input and to solve both parts
+    fn runtime(&self) -> Duration {
+        match &self.outcome {
+            Outcome::Solved(run) => {
+                run.parse_time + run.answers.iter().map(|x| x.elapsed).sum::<Duration>()
+            }
+            _ => Duration::ZERO,
+        }
+    }
+}
+
+/// Runs every solution (of a year) with its own input on a thread pool, then
+/// prints a summary of the answers sorted by runtime, slowest first.
+pub fn run_all(year: Option<u16>, format: Format) -> Result<()> {
+    let start = Instant::now();
+
+    let mut summaries: Vec<Summary> = ENTRIES
+        .par_iter()
+        .filter(|entry| year.is_none_or(|year| entry.year == year))
+        .map(|entry| Summary {
+            entry,
+            outcome: run_entry(entry),
+        })
+        .collect();
+
+    let elapsed = start.elapsed();
+    summaries.sort_by_key(|summary| Reverse(summary.runtime()));
+
+    match format {
+        Format::Text => {
+            print!("{}", format_summary(&summaries));
+            println!("Ran {} solutions in {elapsed:.2?}", summaries.len());
+        }
+        Format::Json => {
+            let reports: Vec<Report> = summaries
+                .iter()
+                .flat_map(|summary| match &summary.outcome {
+                    Outcome::Solved(run) => Report::from_run(summary.entry, run),
+                    Outcome::Locked => vec![Report::unsolved(summary.entry, Status::Locked, None)],
+                    Outcome::Failed(err) => vec![Report::unsolved(
+                        summary.entry,
+                        Status::Failed,
+                        Some(format!("{err:#}")),
+                    )],
+                })
+                .collect();
+
+            println!("{}", serde_json::to_string_pretty(&reports)?);
+        }
+    }
+
+    let mut failures = 0;
+    for summary in &summaries {
+        match &summary.outcome {
+            Outcome::Failed(err) => {
+                eprintln!("{}: {err:#}", describe(summary.entry));
+                failures += 1;
+            }
+            Outcome::Solved(run) => {
+                let errors: Vec<String> = run
+                    .answers
+                    .iter()
+                    .filter_map(|answer| match &answer.answer {
+                        Ok(_) => None,
+                        Err(err) => Some(format!("part {}: {err:#}", answer.part)),
+                    })
+                    .collect();
+                if !errors.is_empty() {
+                    eprintln!("{}: {}", describe(summary.entry), errors.join("; "));
+                    failures += 1;
+                }
+            }
+            Outcome::Locked => {}
+        }
+    }
+
+    if failures > 0 {
+        bail!("{failures} of {} solutions failed", summaries.len());
+    }
+
+    Ok(())
+}
+
+fn run_entry(entry: &Entry) -> Outcome {
+    let input = match entry.default_input().read_to_string() {
+        Ok(input) => input,
+        Err(err)
+            if err
+                .get_ref()
+                .is_some_and(|inner| inner.is::<LockedInputError>()) =>
+        {
+            return Outcome::Locked
+        }
+        Err(err) => return Outcome::Failed(Error::new(err).context("failed to read the input")),
+    };
+
+    match (entry.solve)(&input, &[1, 2]) {
+        Ok(run) => Outcome::Solved(run),
+        Err(err) => Outcome::Failed(err),
+    }
+}
+
+fn format_summary(summaries: &[Summary]) -> String {
+    let rows: Vec<[String; 7]> = summaries
+        .iter()
+        .map(|summary| {
+            let entry = summary.entry;
+            let (status, time) = match &summary.outcome {
+                Outcome::Solved(run) if run.answers.iter().any(|answer| answer.answer.is_err()) => {
+                    ("FAILED", format!("{:.2?}", summary.runtime()))
+                }
+                Outcome::Solved(_) => ("ok", format!("{:.2?}", summary.runtime())),
+                Outcome::Locked => ("locked", String::from("-")),
+                Outcome::Failed(_) => ("FAILED", String::from("-")),
+            };
+            let answer = |part| match &summary.outcome {
+                Outcome::Solved(run) => run
+                    .answers
+                    .iter()
+                    .find(|answer| answer.part == part)
+                    .map_or_else(
+                        || String::from("-"),
+                        |answer| match &answer.answer {
+                            Ok(answer) => answer.clone(),
+                            Err(_) => String::from("error"),
+                        },
+                    ),
+                _ => String::from("-"),
+            };
+
+            [
+                entry.year.to_string(),
+                entry.day.to_string(),
+                entry.variant.unwrap_or("-").to_owned(),
+                status.to_owned(),
+                time,
+                answer(1),
+                answer(2),
+            ]
+        })
+        .collect();
+
+    format_table(
+        [
+            "Year", "Day", "Variant", "Status", "Time", "Part 1", "Part 2",
+        ],
+        &rows,
+    )
+}
+
+fn describe(entry: &Entry) -> String {
+    match entry.variant {
+        Some(variant) => format!(
+            "failed to solve {} day {} ({variant})",
+            entry.year, entry.day
+        ),
+        None => format!("failed to solve {} day {}", entry.year, entry.day),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::registry::Answer;
+
+    #[test]
+    fn test_summary_sorted_by_runtime() {
+        let solved = |millis, answer: &str| {
+            Outcome::Solved(Run {
+                parse_time: Duration::from_millis(millis),
+                answers: vec![Answer {
+                    part: 1,
+                    answer: Ok(String::from(answer)),
+                    elapsed: Duration::from_millis(millis),
+                }],
+            })
+        };
+
+        let mut summaries = vec![
+            Summary {
+                entry: registry::find(2022, 1)[0],
+                outcome: Outcome::Locked,
+            },
+            Summary {
+                entry: registry::find(2023, 6)[0],
+                outcome: solved(1, "288"),
+            },
+            Summary {
+                entry: registry::find(2023, 8)[0],
+                outcome: solved(3, "6"),
+            },
+        ];
+        summaries.sort_by_key(|summary| Reverse(summary.runtime()));
+
+        assert_eq!(
+            "Year  Day  Variant  Status  Time    Part 1  Part 2
+2023  8    -        ok      6.00ms  6       -
+2023  6    -        ok      2.00ms  288     -
+2022  1    -        locked  -       -       -
+",
+            format_summary(&summaries)
+        );
+    }
+
+    #[test]
+    fn test_summary_with_failed_part() {
+        let summaries = vec![Summary {
+            entry: registry::find(2023, 8)[0],
+            outcome: Outcome::Solved(Run {
+                parse_time: Duration::from_millis(1),
+                answers: vec![
+                    Answer {
+                        part: 1,
+                        answer: Err(anyhow::anyhow!("expected a single start node, found 0")),
+                        elapsed: Duration::from_millis(1),
+                    },
+                    Answer {
+                        part: 2,
+                        answer: Ok(String::from("6")),
+                        elapsed: Duration::from_millis(1),
+                    },
+                ],
+            }),
+        }];
+
+        assert_eq!(
+            "Year  Day  Variant  Status  Time    Part 1  Part 2
+2023  8    -        FAILED  3.00ms  error   6
+",
+            format_summary(&summaries)
+        );
+    }
+}
@@ -0,0 +1,237 @@
+use crate::{readme, registry};
+use anyhow::{bail, Context, Result};
+use std::{fs, path::Path};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/day/Cargo.toml.template");
+const LIB_TEMPLATE: &str = include_str!("../templates/day/lib.rs.template");
+const MAIN_TEMPLATE: &str = include_str!("../templates/day/main.rs.template");
+
+/// Creates the crate of a new day from the templates, then registers it as a
+/// dependency and as an entry of the runner, and records its title for the
+/// README.
+///
+/// The workspace picks the crate up by itself, since its members are globs.
+pub fn new_day(year: u16, day: u8, title: &str) -> Result<()> {
+    let root = registry::workspace_dir();
+    let dir = root.join(format!("{year}/day-{day}"));
+
+    if dir.exists() {
+        bail!("{} already exists", dir.display());
+    }
+
+    // Both files are updated in memory first, so that nothing is created when they can't be
+    let manifest_path = root.join("aoc/Cargo.toml");
+    let registry_path = root.join("aoc/src/registry.rs");
+    let manifest = register_dependency(&read(&manifest_path)?, year, day);
+    let registry = register_entry(&read(&registry_path)?, year, day)?;
+
+    fs::create_dir_all(dir.join("src"))
+        .with_context(|| format!("failed to create {}", dir.display()))?;
+
+    for (path, contents) in [
+        ("Cargo.toml", render(CARGO_TEMPLATE, year, day)),
+        ("src/lib.rs", render(LIB_TEMPLATE, year, day)),
+        ("src/main.rs", render(MAIN_TEMPLATE, year, day)),
+        ("demo-input.txt", String::new()),
+    ] {
+        write(&dir.join(path), &contents)?;
+    }
+
+    write(&manifest_path, &manifest)?;
+    write(&registry_path, &registry)?;
+    readme::add_day(year, day, &format!("{year}/day-{day}"), title)?;
+
+    println!("Created {year}/day-{day}");
+    println!("Paste the example of the puzzle into demo-input.txt, then download the input with:");
+    println!("  cargo run -p aoc -- fetch {year} {day}");
+
+    Ok(())
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    fs::write(path, contents).with_context(|| format!("failed to write {}", path.display()))
+}
+
+fn render(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string())
+}
+
+/// Year and day of a dependency line of the runner manifest, e.g.
+/// `aoc-2023-day-1-bis = { path = "../2023/day-1-bis" }`
+fn dependency_day(line: &str) -> Option<(u16, u8)> {
+    let (name, _) = line.split_once(" = ")?;
+    let mut tokens = name.strip_prefix("aoc-")?.split('-');
+
+    let year = tokens.next()?.parse().ok()?;
+    let day = tokens.nth(1)?.parse().ok()?;
+
+    Some((year, day))
+}
+
+/// Adds the crate of the day to the dependencies of the runner, keeping them sorted
+fn register_dependency(manifest: &str, year: u16, day: u8) -> String {
+    let line = format!("aoc-{year}-day-{day} = {{ path = \"../{year}/day-{day}\" }}");
+    let mut lines: Vec<&str> = manifest.lines().collect();
+
+    // Right after the last day that comes before, or else right before the first one
+    let position = match lines
+        .iter()
+        .rposition(|x| dependency_day(x).is_some_and(|x| x <= (year, day)))
+    {
+        Some(idx) => Some(idx + 1),
+        None => lines.iter().position(|x| dependency_day(x).is_some()),
+    };
+
+    match position {
+        Some(idx) => lines.insert(idx, &line),
+        None => {
+            lines.push("");
+            lines.push(&line);
+        }
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// Adds the day to the entries of the registry, keeping them sorted
+fn register_entry(registry: &str, year: u16, day: u8) -> Result<String> {
+    let entry = format!(
+        "    Entry {{
+        year: {year},
+        day: {day},
+        variant: None,
+        dir: \"{year}/day-{day}\",
+        solve: solve::<aoc_{year}_day_{day}::Day{day}>,
+        parts: parts::<aoc_{year}_day_{day}::Day{day}>,
+    }},
+"
+    );
+
+    let start = registry
+        .find("pub const ENTRIES: &[Entry] = &[\n")
+        .context("entries not found in the registry")?;
+    let end = start
+        + registry[start..]
+            .find("\n];")
+            .context("end of the entries not found in the registry")?
+        + 1;
+
+    // Entries begin with their year and day, on the lines following "Entry {"
+    let mut position = end;
+    let mut offset = start;
+    while let Some(idx) = registry[offset..end].find("    Entry {\n") {
+        let block = offset + idx;
+        let mut fields = registry[block..end].lines().skip(1).map(|x| {
+            x.trim()
+                .split_once(": ")
+                .and_then(|(_, value)| value.trim_end_matches(',').parse::<u16>().ok())
+        });
+
+        if let (Some(Some(entry_year)), Some(Some(entry_day))) = (fields.next(), fields.next()) {
+            if (entry_year, entry_day) > (year, day as u16) {
+                position = block;
+                break;
+            }
+        }
+
+        offset = block + 1;
+    }
+
+    Ok(format!(
+        "{}{entry}{}",
+        &registry[..position],
+        &registry[position..]
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MANIFEST: &str = "[dependencies]
+anyhow = \"1.0.75\"
+
+aoc-2022-day-3 = { path = \"../2022/day-3\" }
+aoc-2023-day-1-bis = { path = \"../2023/day-1-bis\" }
+aoc-2023-day-24 = { path = \"../2023/day-24\" }
+";
+
+    const REGISTRY: &str = "pub const ENTRIES: &[Entry] = &[
+    Entry {
+        year: 2023,
+        day: 1,
+        variant: None,
+        dir: \"2023/day-1\",
+        solve: solve::<aoc_2023_day_1::Day1>,
+        parts: parts::<aoc_2023_day_1::Day1>,
+    },
+    Entry {
+        year: 2023,
+        day: 24,
+        variant: Some(\"approach A\"),
+        dir: \"2023/day-24\",
+        solve: solve::<aoc_2023_day_24::Day24>,
+        parts: parts::<aoc_2023_day_24::Day24>,
+    },
+];
+";
+
+    #[test]
+    fn test_register_dependency() {
+        let manifest = register_dependency(MANIFEST, 2023, 5);
+        assert_eq!(
+            "aoc-2023-day-1-bis = { path = \"../2023/day-1-bis\" }
+aoc-2023-day-5 = { path = \"../2023/day-5\" }
+aoc-2023-day-24 = { path = \"../2023/day-24\" }",
+            manifest.lines().skip(4).collect::<Vec<_>>().join("\n")
+        );
+
+        let manifest = register_dependency(MANIFEST, 2015, 1);
+        assert_eq!(
+            "aoc-2015-day-1 = { path = \"../2015/day-1\" }",
+            manifest.lines().nth(3).unwrap()
+        );
+
+        let manifest = register_dependency("[dependencies]\n", 2024, 1);
+        assert_eq!(
+            "[dependencies]\n\naoc-2024-day-1 = { path = \"../2024/day-1\" }\n",
+            manifest
+        );
+    }
+
+    #[test]
+    fn test_register_entry() {
+        let registry = register_entry(REGISTRY, 2023, 5).unwrap();
+        let days: Vec<&str> = registry
+            .lines()
+            .filter(|x| x.trim_start().starts_with("dir: "))
+            .collect();
+
+        assert_eq!(
+            vec![
+                "        dir: \"2023/day-1\",",
+                "        dir: \"2023/day-5\",",
+                "        dir: \"2023/day-24\","
+            ],
+            days
+        );
+        assert!(registry.contains("parts: parts::<aoc_2023_day_5::Day5>,\n    },\n    Entry {"));
+
+        let registry = register_entry(REGISTRY, 2024, 1).unwrap();
+        assert!(registry.ends_with("parts: parts::<aoc_2024_day_1::Day1>,\n    },\n];\n"));
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            "aoc_2023_day_5::Day5",
+            render("aoc_{{year}}_day_{{day}}::Day{{day}}", 2023, 5)
+        );
+    }
+}
@@ -0,0 +1,279 @@
+use crate::{
+    output::format_table,
+    registry::{Answer, Entry, ENTRIES},
+};
+use anyhow::{bail, Result};
+use aoc_common::{AnswerBook, InputSource, LockedInputError};
+use std::fmt::{self, Display};
+
+/// Outcome of the verification of a part
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Status {
+    Pass,
+    Fail,
+    /// No answer recorded for the part yet
+    Unknown,
+    /// The input is still encrypted, so the day couldn't run
+    Locked,
+    /// Reading the input or solving the day failed
+    Error,
+}
+
+impl Status {
+    fn is_regression(self) -> bool {
+        matches!(self, Status::Fail | Status::Error)
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Unknown => "unknown",
+            Status::Locked => "locked",
+            Status::Error => "ERROR",
+        };
+
+        // Padding is left to the table
+        f.pad(status)
+    }
+}
+
+struct Row<'a> {
+    entry: &'a Entry,
+    part: Option<u8>,
+    answer: String,
+    expected: Option<String>,
+    status: Status,
+}
+
+/// Runs every day (of the given year) with its own input and compares the
+/// answers against the answers file, failing when any of them is wrong.
+pub fn verify(year: Option<u16>) -> Result<()> {
+    let entries = ENTRIES
+        .iter()
+        .filter(|entry| year.is_none_or(|year| entry.year == year));
+
+    verify_entries(entries, Entry::default_input)
+}
+
+/// Runs the given days with the inputs chosen by `input`, printing the table
+/// of the answers
+fn verify_entries<'a>(
+    entries: impl IntoIterator<Item = &'a Entry>,
+    input: impl Fn(&Entry) -> InputSource,
+) -> Result<()> {
+    let answers = AnswerBook::load()?;
+
+    let rows: Vec<Row> = entries
+        .into_iter()
+        .flat_map(|entry| verify_entry(entry, &input(entry), &answers))
+        .collect();
+
+    print!("{}", format_rows(&rows));
+
+    let regressions = rows.iter().filter(|row| row.status.is_regression()).count();
+    if regressions > 0 {
+        bail!(
+            "{regressions} of {} answers are wrong or failed",
+            rows.len()
+        );
+    }
+
+    Ok(())
+}
+
+fn verify_entry<'a>(entry: &'a Entry, input: &InputSource, answers: &AnswerBook) -> Vec<Row<'a>> {
+    let failure = |status, message: String| {
+        vec![Row {
+            entry,
+            part: None,
+            answer: message,
+            expected: None,
+            status,
+        }]
+    };
+
+    let input = match input.read_to_string() {
+        Ok(input) => input,
+        Err(err)
+            if err
+                .get_ref()
+                .is_some_and(|inner| inner.is::<LockedInputError>()) =>
+        {
+            return failure(Status::Locked, String::from("-"));
+        }
+        Err(err) => return failure(Status::Error, err.to_string()),
+    };
+
+    match (entry.solve)(&input, &[1, 2]) {
+        Ok(run) => run
+            .answers
+            .into_iter()
+            .map(|Answer { part, answer, .. }| {
+                let expected = answers.get(entry.year, entry.day, part);
+                let (answer, status) = match answer {
+                    Err(err) => (format!("{err:#}"), Status::Error),
+                    Ok(answer) => {
+                        let status = match expected {
+                            Some(expected) if expected == answer => Status::Pass,
+                            Some(_) => Status::Fail,
+                            None => Status::Unknown,
+                        };
+                        (answer, status)
+                    }
+                };
+
+                Row {
+                    entry,
+                    part: Some(part),
+                    answer,
+                    expected: expected.map(String::from),
+                    status,
+                }
+            })
+            .collect(),
+        Err(err) => failure(Status::Error, format!("{err:#}")),
+    }
+}
+
+fn format_rows(rows: &[Row]) -> String {
+    let header = [
+        "Year", "Day", "Part", "Variant", "Answer", "Expected", "Status",
+    ];
+    let cells: Vec<[String; 7]> = rows
+        .iter()
+        .map(|row| {
+            [
+                row.entry.year.to_string(),
+                row.entry.day.to_string(),
+                row.part
+                    .map_or_else(|| String::from("-"), |part| part.to_string()),
+                row.entry.variant.unwrap_or("-").to_owned(),
+                row.answer.clone(),
+                row.expected.clone().unwrap_or_else(|| String::from("-")),
+                row.status.to_string(),
+            ]
+        })
+        .collect();
+
+    format_table(header, &cells)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::registry;
+    use aoc_common::ANSWERS_ENV;
+    use std::{env, fs};
+
+    const ANSWERS: &str = "[2022.1]\npart1 = 4000\npart2 = \"9999\"\n";
+    const ELVES: &[u8] = b"1000\n2000\n\n3000\n\n4000\n";
+    const RUCKSACKS: &[u8] =
+        b"vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwg\n";
+
+    /// Writes an input into a file of its own, as the days read their input from a file
+    fn input(name: &str, contents: &[u8]) -> InputSource {
+        let path = env::temp_dir().join(format!("aoc-verify-{name}.txt"));
+        fs::write(&path, contents).unwrap();
+        InputSource::from(path)
+    }
+
+    fn statuses(rows: &[Row]) -> Vec<(Option<u8>, Status)> {
+        rows.iter().map(|row| (row.part, row.status)).collect()
+    }
+
+    #[test]
+    fn test_regressions() {
+        assert!(Status::Fail.is_regression());
+        assert!(Status::Error.is_regression());
+        assert!(!Status::Unknown.is_regression());
+        assert!(!Status::Locked.is_regression());
+    }
+
+    #[test]
+    fn test_verify_entry() {
+        let answers = AnswerBook::parse(ANSWERS).unwrap();
+        let day_1 = registry::find(2022, 1)[0];
+        let day_3 = registry::find(2022, 3)[0];
+
+        let rows = verify_entry(day_1, &input("2022-1", ELVES), &answers);
+        assert_eq!(
+            vec![(Some(1), Status::Pass), (Some(2), Status::Fail)],
+            statuses(&rows)
+        );
+        assert_eq!("10000", rows[1].answer);
+        assert_eq!(Some("9999"), rows[1].expected.as_deref());
+
+        // No answers recorded for the day
+        let rows = verify_entry(day_3, &input("2022-3", RUCKSACKS), &answers);
+        assert_eq!(
+            vec![(Some(1), Status::Unknown), (Some(2), Status::Unknown)],
+            statuses(&rows)
+        );
+
+        let rows = verify_entry(
+            day_1,
+            &input("locked", b"\0GITCRYPT\0\xef\x49\x8d"),
+            &answers,
+        );
+        assert_eq!(vec![(None, Status::Locked)], statuses(&rows));
+
+        let rows = verify_entry(day_1, &input("invalid", b"1000\n\n20x0\n"), &answers);
+        assert_eq!(vec![(None, Status::Error)], statuses(&rows));
+        assert!(rows[0].answer.contains("line 3"));
+
+        // Only the second part fails
+        let rows = verify_entry(day_1, &input("two-elves", b"1000\n\n2000\n"), &answers);
+        assert_eq!(
+            vec![(Some(1), Status::Fail), (Some(2), Status::Error)],
+            statuses(&rows)
+        );
+        assert_eq!("less than three elves in the input", rows[1].answer);
+    }
+
+    #[test]
+    fn test_format_rows() {
+        let answers = AnswerBook::parse(ANSWERS).unwrap();
+        let day_1 = registry::find(2022, 1)[0];
+
+        let mut rows = verify_entry(day_1, &input("table", ELVES), &answers);
+        rows.extend(verify_entry(
+            day_1,
+            &input("table-locked", b"\0GITCRYPT\0"),
+            &answers,
+        ));
+
+        assert_eq!(
+            "Year  Day  Part  Variant  Answer  Expected  Status\n\
+             2022  1    1     -        4000    4000      pass\n\
+             2022  1    2     -        10000   9999      FAIL\n\
+             2022  1    -     -        -       -         locked\n",
+            format_rows(&rows)
+        );
+    }
+
+    #[test]
+    fn test_verify_fails_on_regression() {
+        let path = env::temp_dir().join("aoc-verify-answers.toml");
+        fs::write(&path, ANSWERS).unwrap();
+        // The only test of the crate reading the answers of the current user
+        env::set_var(ANSWERS_ENV, &path);
+
+        let day_1 = registry::find(2022, 1)[0];
+        let day_3 = registry::find(2022, 3)[0];
+
+        let err = verify_entries([day_1], |_| input("regression", ELVES)).unwrap_err();
+        assert_eq!("1 of 2 answers are wrong or failed", err.to_string());
+
+        // Locked days and unknown answers aren't regressions
+        verify_entries([day_1, day_3], |entry| match entry.day {
+            1 => input("no-regression-1", b"\0GITCRYPT\0"),
+            _ => input("no-regression-3", RUCKSACKS),
+        })
+        .unwrap();
+
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,154 @@
+use anyhow::{bail, Context, Result};
+use std::{
+    collections::HashMap,
+    env,
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+use toml::{Table, Value};
+
+/// Environment variable pointing to the answers file of the current user
+pub const ANSWERS_ENV: &str = "AOC_ANSWERS";
+
+/// Answers file looked up when [`ANSWERS_ENV`] is not set, at the root of the workspace
+const DEFAULT_ANSWERS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.toml");
+
+/// Expected answers of the puzzles, as found with the personal inputs of a user.
+///
+/// The answers file is a TOML table keyed by year and day, e.g.:
+///
+/// ```toml
+/// [2023.4]
+/// part1 = 26914
+/// part2 = "13080971"
+/// ```
+#[derive(Debug, Default)]
+pub struct AnswerBook {
+    answers: HashMap<(u16, u8, u8), String>,
+}
+
+impl AnswerBook {
+    /// Path of the answers file: [`ANSWERS_ENV`] when set, otherwise the one
+    /// of the workspace
+    pub fn path() -> PathBuf {
+        env::var_os(ANSWERS_ENV).map_or_else(|| PathBuf::from(DEFAULT_ANSWERS), PathBuf::from)
+    }
+
+    /// Loads the answers file of the current user; a missing file has no answers.
+    pub fn load() -> Result<Self> {
+        Self::load_from(&Self::path())
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("failed to read the answers from {}", path.display()))?;
+
+        Self::parse(&text)
+            .with_context(|| format!("failed to parse the answers from {}", path.display()))
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut answers = HashMap::new();
+
+        for (year, days) in text.parse::<Table>()? {
+            let days = days
+                .as_table()
+                .with_context(|| format!("year {year} is not a table of days"))?;
+
+            for (day, parts) in days {
+                let parts = parts
+                    .as_table()
+                    .with_context(|| format!("day {year}.{day} is not a table of parts"))?;
+
+                for (part, answer) in parts {
+                    let key = (
+                        parse_key(&year, "year")?,
+                        parse_key(day, "day")?,
+                        match part.as_str() {
+                            "part1" => 1,
+                            "part2" => 2,
+                            _ => bail!("unknown part {part:?} of day {year}.{day}"),
+                        },
+                    );
+                    let answer = match answer {
+                        Value::String(answer) => answer.clone(),
+                        Value::Integer(answer) => answer.to_string(),
+                        _ => bail!("answer of {year}.{day}.{part} must be a number or a string"),
+                    };
+
+                    answers.insert(key, answer);
+                }
+            }
+        }
+
+        Ok(AnswerBook { answers })
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(year, day, part)).map(String::as_str)
+    }
+}
+
+fn parse_key<T: std::str::FromStr>(key: &str, name: &str) -> Result<T> {
+    key.parse()
+        .ok()
+        .with_context(|| format!("invalid {name} {key:?}"))
+}
+
+/// Checks the answer found for a personal input against the answers file.
+///
+/// The check is skipped when the file has no answer for that part, so that
+/// tests don't fail for users who didn't record their answers yet.
+#[track_caller]
+pub fn assert_answer(year: u16, day: u8, part: u8, answer: Option<impl Display>) {
+    static ANSWERS: OnceLock<AnswerBook> = OnceLock::new();
+
+    let answers =
+        ANSWERS.get_or_init(|| AnswerBook::load().unwrap_or_else(|err| panic!("{err:#}")));
+    let answer = answer.map(|answer| answer.to_string());
+
+    match answers.get(year, day, part) {
+        Some(expected) => assert_eq!(
+            Some(expected),
+            answer.as_deref(),
+            "wrong answer for {year} day {day} part {part}"
+        ),
+        None => eprintln!("skipped, no answer recorded for {year} day {day} part {part}"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = AnswerBook::parse(
+            "[2023.4]
+part1 = 26914
+part2 = \"13080971\"
+
+[2022]
+3 = { part2 = 2821 }",
+        )
+        .unwrap();
+
+        assert_eq!(Some("26914"), answers.get(2023, 4, 1));
+        assert_eq!(Some("13080971"), answers.get(2023, 4, 2));
+        assert_eq!(Some("2821"), answers.get(2022, 3, 2));
+        assert_eq!(None, answers.get(2022, 3, 1));
+    }
+
+    #[test]
+    fn test_invalid_answers() {
+        let err = AnswerBook::parse("[2023.4]\npart3 = 1").unwrap_err();
+
+        assert_eq!("unknown part \"part3\" of day 2023.4", err.to_string());
+    }
+}
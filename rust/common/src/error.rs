@@ -0,0 +1,148 @@
+use anyhow::{Error, Result};
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// Error raised while parsing the puzzle input, pointing at the line (and at the
+/// column, when it's known) of the input text where it happened.
+#[derive(Debug)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: Option<usize>,
+    source: Error,
+}
+
+impl ParseError {
+    /// Wraps an error raised while parsing the given line (1-based) of the input.
+    ///
+    /// If the error was caused by a [`TokenError`] about a slice of `text`,
+    /// the column of the token is reported as well.
+    pub fn new(line: usize, text: &str, source: Error) -> Self {
+        let column = source
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<TokenError>())
+            .and_then(|token| token.column_within(text));
+
+        ParseError {
+            line,
+            column,
+            source,
+        }
+    }
+
+    /// Wraps an error raised at a known line and column (both 1-based) of the input.
+    pub fn at(line: usize, column: usize, source: Error) -> Self {
+        ParseError {
+            line,
+            column: Some(column),
+            source,
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.column {
+            Some(column) => write!(
+                f,
+                "line {}, column {}: {:#}",
+                self.line, column, self.source
+            ),
+            None => write!(f, "line {}: {:#}", self.line, self.source),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Error about a single token of an input line.
+///
+/// Tokens are slices of the line being parsed, so their column is recovered
+/// from their address once the error reaches [`ParseError::new`]; this keeps
+/// nested `FromStr` implementations unaware of where their text comes from.
+#[derive(Debug)]
+pub struct TokenError {
+    address: usize,
+    message: String,
+}
+
+impl TokenError {
+    pub fn new(token: &str, message: impl Display) -> Self {
+        TokenError {
+            address: token.as_ptr() as usize,
+            message: message.to_string(),
+        }
+    }
+
+    fn column_within(&self, text: &str) -> Option<usize> {
+        let start = text.as_ptr() as usize;
+
+        (start..=start + text.len())
+            .contains(&self.address)
+            .then(|| text[..self.address - start].chars().count() + 1)
+    }
+}
+
+impl Display for TokenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for TokenError {}
+
+/// Parses each line of the input, reporting the position of the first failure.
+pub fn parse_lines<T>(
+    input: &str,
+    mut parse: impl FnMut(&str) -> Result<T>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| parse(line).map_err(|err| ParseError::new(idx + 1, line, err)))
+        .collect()
+}
+
+/// Parses a token of an input line (e.g. a number), failing with a [`TokenError`].
+pub fn parse_token<T>(token: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    token
+        .parse()
+        .map_err(|err| TokenError::new(token, format!("invalid value {token:?} ({err})")).into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn test_error_position() {
+        let err = parse_lines("1 2\n3 x4", |line| {
+            line.split(' ')
+                .map(parse_token::<u8>)
+                .collect::<Result<Vec<_>>>()
+                .context("failed to parse numbers")
+        })
+        .unwrap_err();
+
+        assert_eq!(2, err.line);
+        assert_eq!(Some(3), err.column);
+        assert_eq!(
+            "line 2, column 3: failed to parse numbers: invalid value \"x4\" (invalid digit found in string)",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_error_without_token() {
+        let err = ParseError::new(7, "foo", anyhow::anyhow!("something went wrong"));
+
+        assert_eq!(None, err.column);
+        assert_eq!("line 7: something went wrong", err.to_string());
+    }
+}
@@ -0,0 +1,298 @@
+use crate::ParseError;
+use anyhow::{anyhow, Result};
+use std::{
+    collections::{HashSet, VecDeque},
+    ops::{Index, IndexMut, Range},
+};
+
+/// Position of a cell, as (row, col)
+pub type Position = (usize, usize);
+
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const ALL_AROUND: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Which cells around a position count as its neighbours
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Neighbours {
+    /// Up, left, right and down
+    Four,
+    /// Including diagonals
+    Eight,
+}
+
+/// Horizontal run of adjacent cells within a row
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span {
+    pub row: usize,
+    pub cols: Range<usize>,
+}
+
+impl Span {
+    pub fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        self.cols.clone().map(|col| (self.row, col))
+    }
+}
+
+/// Rectangular 2D grid, stored row by row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells, row by row
+    pub fn new(rows: usize, cols: usize, cells: Vec<T>) -> Self {
+        assert_eq!(rows * cols, cells.len(), "cells don't fill the grid");
+
+        Grid { rows, cols, cells }
+    }
+
+    /// Parses a grid with one row per line and one cell per char, reporting the
+    /// position of invalid cells. Empty lines at the end are ignored.
+    pub fn parse(
+        input: &str,
+        mut parse: impl FnMut(char) -> Result<T>,
+    ) -> Result<Self, ParseError> {
+        let lines: Vec<&str> = input.trim_end_matches(['\n', '\r']).lines().collect();
+        let cols = lines.first().map_or(0, |line| line.chars().count());
+        let mut cells = Vec::with_capacity(lines.len() * cols);
+
+        for (idx, line) in lines.iter().enumerate() {
+            let count = line.chars().count();
+            if count != cols {
+                let err = anyhow!("expected {cols} cells like the first row, found {count}");
+                return Err(ParseError::new(idx + 1, line, err));
+            }
+
+            for (col, char) in line.chars().enumerate() {
+                let cell = parse(char)
+                    .map_err(|err| ParseError::at(idx + 1, col + 1, err.context("invalid cell")))?;
+                cells.push(cell);
+            }
+        }
+
+        Ok(Grid::new(lines.len(), cols, cells))
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get(&self, (row, col): Position) -> Option<&T> {
+        (row < self.rows && col < self.cols).then(|| &self.cells[row * self.cols + col])
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Cells with their positions, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(idx, cell)| ((idx / self.cols, idx % self.cols), cell))
+    }
+
+    /// Positions around the given one that are within the grid
+    pub fn neighbours(
+        &self,
+        (row, col): Position,
+        neighbours: Neighbours,
+    ) -> impl Iterator<Item = Position> + '_ {
+        let offsets: &[(isize, isize)] = match neighbours {
+            Neighbours::Four => &ORTHOGONAL,
+            Neighbours::Eight => &ALL_AROUND,
+        };
+
+        offsets.iter().filter_map(move |&(d_row, d_col)| {
+            let row = row.checked_add_signed(d_row)?;
+            let col = col.checked_add_signed(d_col)?;
+
+            (row < self.rows && col < self.cols).then_some((row, col))
+        })
+    }
+
+    /// Horizontal runs of adjacent cells matching the predicate, e.g. the
+    /// numbers within a grid of chars
+    pub fn runs(&self, mut matches: impl FnMut(&T) -> bool) -> Vec<Span> {
+        let mut runs = Vec::new();
+
+        for row in 0..self.rows {
+            let mut start = None;
+
+            for (col, cell) in self.row(row).iter().enumerate() {
+                match (matches(cell), start) {
+                    (true, None) => start = Some(col),
+                    (false, Some(first)) => {
+                        runs.push(Span {
+                            row,
+                            cols: first..col,
+                        });
+                        start = None;
+                    }
+                    _ => {}
+                }
+            }
+
+            if let Some(first) = start {
+                runs.push(Span {
+                    row,
+                    cols: first..self.cols,
+                });
+            }
+        }
+
+        runs
+    }
+
+    /// Positions of the cells matching the predicate that are connected to the
+    /// starting one, in the order they are reached (breadth first)
+    pub fn region(
+        &self,
+        start: Position,
+        neighbours: Neighbours,
+        mut matches: impl FnMut(&T) -> bool,
+    ) -> Vec<Position> {
+        if !self.get(start).is_some_and(&mut matches) {
+            return Vec::new();
+        }
+
+        let mut region = vec![start];
+        let mut seen = HashSet::from([start]);
+        let mut queue = VecDeque::from([start]);
+
+        while let Some(position) = queue.pop_front() {
+            for next in self.neighbours(position, neighbours) {
+                if matches(&self[next]) && seen.insert(next) {
+                    region.push(next);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        region
+    }
+
+    /// Grid of the same size, with the cells transformed by the function
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.rows, self.cols, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("position {position:?} out of the grid"))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, (row, col): Position) -> &mut T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "position {:?} out of the grid",
+            (row, col)
+        );
+
+        &mut self.cells[row * self.cols + col]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use anyhow::bail;
+
+    const EXAMPLE: &str = "12.
+.#4
+";
+
+    fn chars() -> Grid<char> {
+        Grid::parse(EXAMPLE, Ok).unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_index() {
+        let grid = chars();
+
+        assert_eq!((2, 3), (grid.rows(), grid.cols()));
+        assert_eq!('#', grid[(1, 1)]);
+        assert_eq!(Some(&'4'), grid.get((1, 2)));
+        assert_eq!(None, grid.get((2, 0)));
+        assert_eq!(&['.', '#', '4'], grid.row(1));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Grid::parse("12.\n.#", Ok).unwrap_err();
+        assert_eq!(
+            "line 2: expected 3 cells like the first row, found 2",
+            err.to_string()
+        );
+
+        let err = Grid::parse("12.\n.#x", |c| match c {
+            'x' => bail!("unknown cell {c:?}"),
+            c => Ok(c),
+        })
+        .unwrap_err();
+        assert_eq!(
+            "line 2, column 3: invalid cell: unknown cell 'x'",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = chars();
+
+        assert_eq!(
+            vec![(0, 1), (1, 0)],
+            grid.neighbours((0, 0), Neighbours::Four)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 2)],
+            grid.neighbours((1, 1), Neighbours::Eight)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_runs_and_regions() {
+        let grid = chars();
+
+        assert_eq!(
+            vec![Span { row: 0, cols: 0..2 }, Span { row: 1, cols: 2..3 }],
+            grid.runs(char::is_ascii_digit)
+        );
+        assert_eq!(
+            vec![(0, 1), (0, 0)],
+            grid.region((0, 1), Neighbours::Four, char::is_ascii_digit)
+        );
+        assert_eq!(
+            vec![(0, 1), (0, 0), (1, 2)],
+            grid.region((0, 1), Neighbours::Eight, char::is_ascii_digit)
+        );
+        assert!(grid
+            .region((1, 1), Neighbours::Eight, char::is_ascii_digit)
+            .is_empty());
+    }
+}
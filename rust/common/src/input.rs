@@ -0,0 +1,160 @@
+use std::{
+    env,
+    fmt::{self, Display},
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+    sync::OnceLock,
+};
+
+/// Name of the input file looked up when no other source is given
+pub const DEFAULT_INPUT: &str = "input.txt";
+
+/// Header of the files encrypted by git-crypt, which stay as they are until
+/// the repository is unlocked with the key
+const GIT_CRYPT_HEADER: &[u8] = b"\0GITCRYPT\0";
+
+/// Where the puzzle input is read from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Reads the source from the first command line argument: either a file path,
+    /// or "-" to read from stdin. Without arguments, falls back to "input.txt".
+    pub fn from_args() -> Self {
+        env::args()
+            .nth(1)
+            .map_or_else(|| Self::from(DEFAULT_INPUT), |arg| Self::from(arg.as_str()))
+    }
+
+    /// Reads the whole input, failing with a [`LockedInputError`] when it's
+    /// still encrypted by git-crypt.
+    pub fn read_to_string(&self) -> io::Result<String> {
+        let contents = match self {
+            InputSource::File(path) => fs::read(path)?,
+            InputSource::Stdin => read_stdin()?,
+        };
+
+        if contents.starts_with(GIT_CRYPT_HEADER) {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, LockedInputError));
+        }
+
+        String::from_utf8(contents).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+impl From<&str> for InputSource {
+    fn from(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+}
+
+impl From<PathBuf> for InputSource {
+    fn from(path: PathBuf) -> Self {
+        InputSource::File(path)
+    }
+}
+
+/// Error raised when reading an input that is still encrypted by git-crypt.
+#[derive(Debug)]
+pub struct LockedInputError;
+
+impl Display for LockedInputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "inputs are locked, run `git-crypt unlock` to decrypt them"
+        )
+    }
+}
+
+impl std::error::Error for LockedInputError {}
+
+/// Reads a personal input file for tests.
+///
+/// Returns `None` when the file is still encrypted, so that tests checking the
+/// answers of real inputs are skipped on clones without the git-crypt key, and
+/// when it's missing, as for days whose input wasn't downloaded yet.
+pub fn unlocked_input(path: &str) -> Option<String> {
+    match InputSource::from(path).read_to_string() {
+        Ok(input) => Some(input),
+        Err(err) if is_locked(&err) => {
+            eprintln!("skipped, {path} is encrypted: {err}");
+            None
+        }
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            eprintln!("skipped, {path} not found");
+            None
+        }
+        Err(err) => panic!("failed to read the input from {path}: {err}"),
+    }
+}
+
+fn is_locked(err: &io::Error) -> bool {
+    err.get_ref()
+        .is_some_and(|inner| inner.is::<LockedInputError>())
+}
+
+/// Stdin can only be consumed once, so it's kept around for all the days
+/// that get to parse it (e.g. both "day-1" and "day-1-bis" in the runner)
+fn read_stdin() -> io::Result<Vec<u8>> {
+    static STDIN: OnceLock<Vec<u8>> = OnceLock::new();
+
+    if let Some(contents) = STDIN.get() {
+        return Ok(contents.clone());
+    }
+
+    let mut contents = Vec::new();
+    io::stdin().read_to_end(&mut contents)?;
+
+    Ok(STDIN.get_or_init(|| contents).clone())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_source_from_arg() {
+        assert_eq!(InputSource::Stdin, InputSource::from("-"));
+        assert_eq!(
+            InputSource::File(PathBuf::from("demo-input.txt")),
+            InputSource::from("demo-input.txt")
+        );
+    }
+
+    #[test]
+    fn test_locked_input() {
+        let path = env::temp_dir().join("aoc-common-locked-input.txt");
+        fs::write(&path, b"\0GITCRYPT\0\xef\x49\x8d").unwrap();
+
+        let err = InputSource::from(path.clone())
+            .read_to_string()
+            .unwrap_err();
+        assert!(is_locked(&err));
+        assert_eq!(
+            "inputs are locked, run `git-crypt unlock` to decrypt them",
+            err.to_string()
+        );
+
+        assert_eq!(None, unlocked_input(path.to_str().unwrap()));
+
+        fs::remove_file(&path).unwrap();
+        assert_eq!(None, unlocked_input(path.to_str().unwrap()));
+    }
+}
@@ -0,0 +1,46 @@
+mod answers;
+mod error;
+mod grid;
+mod input;
+pub mod math;
+
+pub use answers::{assert_answer, AnswerBook, ANSWERS_ENV};
+pub use error::{parse_lines, parse_token, ParseError, TokenError};
+pub use grid::{Grid, Neighbours, Position, Span};
+pub use input::{unlocked_input, InputSource, LockedInputError, DEFAULT_INPUT};
+
+use anyhow::Result;
+use std::fmt::Display;
+
+/// Common interface implemented by the solution of every day.
+///
+/// The input is parsed once and then shared by both parts. Days that only solve
+/// one of the two parts (e.g. when the other one lives in a "-bis" crate) keep
+/// the default implementation, which returns no answer, and can set the answer
+/// type of the missing part to [`std::convert::Infallible`].
+pub trait Solution {
+    /// Puzzle input, as parsed from the text of the input file
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    fn part1(_input: &Self::Input) -> Result<Option<Self::Answer1>> {
+        Ok(None)
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Option<Self::Answer2>> {
+        Ok(None)
+    }
+
+    /// Parses the input and solves part 1 in one go
+    fn solve_part1(input: &str) -> Result<Option<Self::Answer1>> {
+        Self::part1(&Self::parse(input)?)
+    }
+
+    /// Parses the input and solves part 2 in one go
+    fn solve_part2(input: &str) -> Result<Option<Self::Answer2>> {
+        Self::part2(&Self::parse(input)?)
+    }
+}
@@ -0,0 +1,238 @@
+//! Number theory helpers: gcd/lcm, extended Euclid, modular arithmetic and
+//! the Chinese Remainder Theorem, as needed by cycle-alignment puzzles.
+
+use anyhow::{Context, Result};
+
+/// Integers supported by [`gcd`] and [`lcm`].
+///
+/// Results are expressed as the unsigned type of the same width, so that
+/// e.g. `gcd(i128::MIN, 0)` (which is 2^127) is still representable.
+pub trait Integer: Copy {
+    type Unsigned: Copy;
+
+    fn gcd(self, other: Self) -> Self::Unsigned;
+    fn lcm(self, other: Self) -> Option<Self::Unsigned>;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty => $u:ty),*) => {$(
+        impl Integer for $t {
+            type Unsigned = $u;
+
+            fn gcd(self, other: Self) -> $u {
+                let (mut a, mut b) = (self.unsigned_abs(), other.unsigned_abs());
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                a
+            }
+
+            fn lcm(self, other: Self) -> Option<$u> {
+                if self == 0 || other == 0 {
+                    return Some(0);
+                }
+                // Divide before multiplying, so that only a result which
+                // doesn't fit overflows
+                (self.unsigned_abs() / self.gcd(other)).checked_mul(other.unsigned_abs())
+            }
+        }
+    )*};
+}
+
+/// `unsigned_abs` of an unsigned integer is the integer itself
+trait UnsignedAbs {
+    fn unsigned_abs(self) -> Self;
+}
+
+impl UnsignedAbs for u64 {
+    fn unsigned_abs(self) -> Self {
+        self
+    }
+}
+
+impl UnsignedAbs for u128 {
+    fn unsigned_abs(self) -> Self {
+        self
+    }
+}
+
+impl_integer!(u64 => u64, u128 => u128, i64 => u64, i128 => u128);
+
+/// Greatest common divisor, always non-negative; `gcd(0, 0)` is 0.
+pub fn gcd<T: Integer>(a: T, b: T) -> T::Unsigned {
+    a.gcd(b)
+}
+
+/// Least common multiple, always non-negative; `None` when it overflows.
+///
+/// The lcm with 0 is 0.
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T::Unsigned> {
+    a.lcm(b)
+}
+
+/// Extended Euclid: returns `(g, x, y)` such that `a * x + b * y = g`, where
+/// `g` is the non-negative gcd of `a` and `b`.
+///
+/// Neither `a` nor `b` can be `i128::MIN`, whose absolute value doesn't fit.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Inverse of `a` modulo `modulus`, in `0..modulus`; `None` when `a` and
+/// `modulus` aren't coprime.
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+    assert!(modulus > 0, "modulus must be positive");
+
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// `a * b` modulo `modulus`, in `0..modulus`, without overflowing even when
+/// the product doesn't fit in an `i128`.
+pub fn mod_mul(a: i128, b: i128, modulus: i128) -> i128 {
+    assert!(modulus > 0, "modulus must be positive");
+
+    let (a, b) = (a.rem_euclid(modulus), b.rem_euclid(modulus));
+    if let Some(product) = a.checked_mul(b) {
+        return product % modulus;
+    }
+
+    // Double-and-add: every intermediate value stays below 2 * modulus,
+    // which fits in an u128
+    let (mut a, mut b, modulus) = (a as u128, b as u128, modulus as u128);
+    let mut result = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            result = (result + a) % modulus;
+        }
+        a = (a << 1) % modulus;
+        b >>= 1;
+    }
+    result as i128
+}
+
+/// `base` raised to `exp` modulo `modulus`, by repeated squaring.
+pub fn mod_pow(base: i128, mut exp: u64, modulus: i128) -> i128 {
+    assert!(modulus > 0, "modulus must be positive");
+
+    let mut base = base.rem_euclid(modulus);
+    let mut result = 1 % modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mod_mul(result, base, modulus);
+        }
+        base = mod_mul(base, base, modulus);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Solves the system `x ≡ residue (mod modulus)` for each `(residue, modulus)`
+/// pair with the Chinese Remainder Theorem; the moduli don't need to be coprime.
+///
+/// Returns `(x, m)`, where `m` is the lcm of the moduli and `x` the smallest
+/// solution in `0..m`, so that every solution is `x + k * m`; `None` when the
+/// congruences contradict each other. An empty system is solved by any `x`,
+/// i.e. `(0, 1)`. Fails when the lcm of the moduli overflows an `i128`.
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Result<Option<(i128, i128)>> {
+    let mut solution: (i128, i128) = (0, 1);
+
+    for (residue, modulus) in congruences {
+        assert!(modulus > 0, "modulus must be positive");
+
+        let (x, m) = solution;
+        let residue = residue.rem_euclid(modulus);
+
+        // x + m * k ≡ residue (mod modulus) is solvable only when the gcd
+        // divides the difference, and then:
+        // (m / g) * k ≡ (residue - x) / g (mod modulus / g)
+        let (g, inverse, _) = extended_gcd(m, modulus);
+        let difference = residue - x.rem_euclid(modulus);
+        if difference % g != 0 {
+            return Ok(None);
+        }
+
+        let step = modulus / g;
+        let k = mod_mul(difference / g, inverse, step);
+        let combined = m
+            .checked_mul(step)
+            .with_context(|| format!("the lcm of moduli {m} and {modulus} overflows"))?;
+
+        // m * k < m * step, which was just checked to fit
+        solution = ((x + m * k).rem_euclid(combined), combined);
+    }
+
+    Ok(Some(solution))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_gcd_and_lcm() {
+        assert_eq!(6, gcd(12u64, 18));
+        assert_eq!(6, gcd(-12i128, 18));
+        assert_eq!(5, gcd(0u64, 5));
+        assert_eq!(0, gcd(0u64, 0));
+        assert_eq!(1u128 << 127, gcd(i128::MIN, 0));
+
+        assert_eq!(Some(36), lcm(12u64, 18));
+        assert_eq!(Some(36), lcm(-12i64, 18));
+        assert_eq!(Some(0), lcm(0u64, 7));
+        assert_eq!(Some(u64::MAX), lcm(u64::MAX, u64::MAX));
+        assert_eq!(None, lcm(u64::MAX, u64::MAX - 1));
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240, 46), (-240, 46), (46, -240), (0, 7), (7, 0), (17, 17)] {
+            let (g, x, y) = extended_gcd(a, b);
+
+            assert_eq!(gcd(a, b) as i128, g);
+            assert_eq!(g, a * x + b * y);
+        }
+    }
+
+    #[test]
+    fn test_modular_arithmetic() {
+        assert_eq!(Some(4), mod_inverse(3, 11));
+        assert_eq!(Some(7), mod_inverse(-3, 11));
+        assert_eq!(None, mod_inverse(4, 8));
+
+        assert_eq!(445, mod_pow(4, 13, 497));
+        assert_eq!(0, mod_pow(5, 0, 1));
+        assert_eq!(i128::MAX - 1, mod_pow(i128::MAX - 1, u64::MAX, i128::MAX));
+
+        let big = i128::MAX - 1;
+        assert_eq!(1, mod_mul(big, big, i128::MAX));
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(Some((23, 105)), crt([(2, 3), (3, 5), (2, 7)]).unwrap());
+        // Non-coprime moduli
+        assert_eq!(Some((10, 12)), crt([(4, 6), (2, 4)]).unwrap());
+        assert_eq!(None, crt([(1, 6), (2, 4)]).unwrap());
+        assert_eq!(Some((0, 1)), crt([]).unwrap());
+
+        let big = 1i128 << 60;
+        assert!(crt([(1, big), (0, big + 1)]).unwrap().is_some());
+        assert!(crt([(1, big), (0, big + 1), (0, big + 3)]).is_err());
+    }
+}